@@ -0,0 +1,196 @@
+use super::{Abelian, ComMonoid, Group, Monoid};
+/// Pseudo random number generator xoshiro256** seeded by splitmix64.
+///
+/// # Example
+///
+/// ```
+/// use algebra::laws::Rng;
+/// let mut rng = Rng::new(1);
+/// let x = rng.range(10);
+/// assert!(x < 10);
+/// assert_eq!(Rng::new(1).range(10), x);
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct Rng {
+    s: [u64; 4],
+}
+impl Rng {
+    /// This is initializer of `Rng` by `seed`.
+    /// This function has a time complexity of O(1).
+    pub fn new(seed: u64) -> Self {
+        let mut x = seed;
+        let mut splitmix = || {
+            x = x.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut z = x;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            z ^ (z >> 31)
+        };
+        Self {
+            s: [splitmix(), splitmix(), splitmix(), splitmix()],
+        }
+    }
+    /// Return a uniformly random `u64`.
+    /// This function has a time complexity of O(1).
+    pub fn next_u64(&mut self) -> u64 {
+        let res = self.s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = self.s[1] << 17;
+        self.s[2] ^= self.s[0];
+        self.s[3] ^= self.s[1];
+        self.s[1] ^= self.s[2];
+        self.s[0] ^= self.s[3];
+        self.s[2] ^= t;
+        self.s[3] = self.s[3].rotate_left(45);
+        res
+    }
+    /// Return a random integer in `[0, n)`.
+    /// This function has a time complexity of O(1).
+    pub fn range(&mut self, n: usize) -> usize {
+        assert!(n > 0);
+        ((self.next_u64() as u128 * n as u128) >> 64) as usize
+    }
+}
+/// Counterexample of a law of a monoid or a group.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Violation<S> {
+    /// `op(op(a, b), c) != op(a, op(b, c))`.
+    Associativity(S, S, S),
+    /// `op(E, a) != a`.
+    LeftIdentity(S),
+    /// `op(a, E) != a`.
+    RightIdentity(S),
+    /// `op(inv(a), a) != E`.
+    LeftInverse(S),
+    /// `op(a, inv(a)) != E`.
+    RightInverse(S),
+    /// `op(a, b) != op(b, a)`.
+    Commutativity(S, S),
+}
+/// Check associativity and identity of `T` on `trials` samples by `sample`.
+/// Return the first counterexample found.
+///
+/// # Example
+///
+/// ```
+/// use algebra::Monoid;
+/// use algebra::laws::{Violation, check_monoid};
+/// struct Sub;
+/// impl Monoid for Sub {
+///     type S = i64;
+///     const E: i64 = 0;
+///     fn op(lhs: &i64, rhs: &i64) -> i64 {
+///         lhs - rhs
+///     }
+/// }
+/// let res = check_monoid::<Sub>(1, 100, |rng| rng.range(10) as i64);
+/// assert!(matches!(res, Err(Violation::Associativity(..) | Violation::LeftIdentity(_))));
+/// ```
+///
+/// This function has a time complexity of O(trials).
+pub fn check_monoid<T: Monoid>(
+    seed: u64,
+    trials: usize,
+    sample: impl FnMut(&mut Rng) -> T::S,
+) -> Result<(), Violation<T::S>>
+where
+    T::S: PartialEq,
+{
+    monoid_laws(&T::E, T::op, seed, trials, sample)
+}
+/// Check the laws of `check_monoid` and commutativity of `T`.
+/// Return the first counterexample found.
+/// This function has a time complexity of O(trials).
+pub fn check_com_monoid<T: ComMonoid>(
+    seed: u64,
+    trials: usize,
+    mut sample: impl FnMut(&mut Rng) -> T::S,
+) -> Result<(), Violation<T::S>>
+where
+    T::S: PartialEq,
+{
+    monoid_laws(&T::E, T::op, seed, trials, &mut sample)?;
+    commutative_laws(T::op, seed, trials, sample)
+}
+/// Check associativity, identity and inverse of `T` on `trials` samples by `sample`.
+/// Return the first counterexample found.
+///
+/// # Example
+///
+/// ```
+/// use algebra::Additive;
+/// use algebra::laws::check_group;
+/// assert_eq!(check_group::<Additive<i64>>(1, 100, |rng| rng.next_u64() as i32 as i64), Ok(()));
+/// ```
+///
+/// This function has a time complexity of O(trials).
+pub fn check_group<T: Group>(
+    seed: u64,
+    trials: usize,
+    mut sample: impl FnMut(&mut Rng) -> T::S,
+) -> Result<(), Violation<T::S>> {
+    monoid_laws(&T::E, T::op, seed, trials, &mut sample)?;
+    let mut rng = Rng::new(seed);
+    for _ in 0..trials {
+        let a = sample(&mut rng);
+        let b = T::inv(&a);
+        if T::op(&b, &a) != T::E {
+            return Err(Violation::LeftInverse(a));
+        }
+        if T::op(&a, &b) != T::E {
+            return Err(Violation::RightInverse(a));
+        }
+    }
+    Ok(())
+}
+/// Check the laws of `check_group` and commutativity of `T`.
+/// Return the first counterexample found.
+/// This function has a time complexity of O(trials).
+pub fn check_abelian<T: Abelian>(
+    seed: u64,
+    trials: usize,
+    mut sample: impl FnMut(&mut Rng) -> T::S,
+) -> Result<(), Violation<T::S>> {
+    check_group::<T>(seed, trials, &mut sample)?;
+    commutative_laws(T::op, seed, trials, sample)
+}
+fn monoid_laws<S: Clone + PartialEq>(
+    e: &S,
+    op: impl Fn(&S, &S) -> S,
+    seed: u64,
+    trials: usize,
+    mut sample: impl FnMut(&mut Rng) -> S,
+) -> Result<(), Violation<S>> {
+    let mut rng = Rng::new(seed);
+    for _ in 0..trials {
+        let a = sample(&mut rng);
+        let b = sample(&mut rng);
+        let c = sample(&mut rng);
+        if op(e, &a) != a {
+            return Err(Violation::LeftIdentity(a));
+        }
+        if op(&a, e) != a {
+            return Err(Violation::RightIdentity(a));
+        }
+        if op(&op(&a, &b), &c) != op(&a, &op(&b, &c)) {
+            return Err(Violation::Associativity(a, b, c));
+        }
+    }
+    Ok(())
+}
+fn commutative_laws<S: Clone + PartialEq>(
+    op: impl Fn(&S, &S) -> S,
+    seed: u64,
+    trials: usize,
+    mut sample: impl FnMut(&mut Rng) -> S,
+) -> Result<(), Violation<S>> {
+    let mut rng = Rng::new(seed);
+    for _ in 0..trials {
+        let a = sample(&mut rng);
+        let b = sample(&mut rng);
+        if op(&a, &b) != op(&b, &a) {
+            return Err(Violation::Commutativity(a, b));
+        }
+    }
+    Ok(())
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "laws")]
+pub mod laws;
 /// monoid trait.
 ///
 /// # Example
@@ -18,10 +20,7 @@ pub trait ComMonoid: Monoid {}
 /// ```
 /// ```
 ///
-pub trait Group {
-    type S: Clone + PartialEq;
-    const E: Self::S;
-    fn op(lhs: &Self::S, rhs: &Self::S) -> Self::S;
+pub trait Group: Monoid<S: PartialEq> {
     fn inv(val: &Self::S) -> Self::S;
 }
 /// abelian trait.
@@ -32,3 +31,321 @@ pub trait Group {
 /// ```
 ///
 pub trait Abelian: Group {}
+/// semiring trait.
+///
+/// # Example
+///
+/// ```
+/// ```
+///
+pub trait Semiring {
+    type S: Clone;
+    const ZERO: Self::S;
+    const ONE: Self::S;
+    fn add(lhs: &Self::S, rhs: &Self::S) -> Self::S;
+    fn mul(lhs: &Self::S, rhs: &Self::S) -> Self::S;
+}
+/// Numbers are semirings of the ordinary addition and multiplication.
+macro_rules! semiring_impl {
+    ($zero:expr, $one:expr; $($t:ty), *) => {
+        $(
+            impl Semiring for $t {
+                type S = $t;
+                const ZERO: $t = $zero;
+                const ONE: $t = $one;
+                fn add(lhs: &$t, rhs: &$t) -> $t {
+                    lhs + rhs
+                }
+                fn mul(lhs: &$t, rhs: &$t) -> $t {
+                    lhs * rhs
+                }
+            }
+        )*
+    };
+}
+semiring_impl!(
+    0, 1; i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+semiring_impl!(0.0, 1.0; f64);
+/// Numbers which have the minimum and the maximum.
+pub trait Bounded: Copy + PartialOrd + std::ops::Add<Output = Self> + Semiring<S = Self> {
+    const MIN: Self;
+    const MAX: Self;
+}
+macro_rules! bounded_impl {
+    ($($t:ty), *) => {
+        $(
+            impl Bounded for $t {
+                const MIN: Self = <$t>::MIN;
+                const MAX: Self = <$t>::MAX;
+            }
+        )*
+    };
+}
+bounded_impl!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+impl Bounded for f64 {
+    const MIN: Self = f64::NEG_INFINITY;
+    const MAX: Self = f64::INFINITY;
+}
+/// tropical semiring (min, +).
+/// `T::MAX` is regarded as infinity.
+pub struct MinPlus<T>(std::marker::PhantomData<T>);
+impl<T: Bounded> Semiring for MinPlus<T> {
+    type S = T;
+    const ZERO: T = T::MAX;
+    const ONE: T = T::ZERO;
+    fn add(lhs: &T, rhs: &T) -> T {
+        if lhs < rhs { *lhs } else { *rhs }
+    }
+    fn mul(lhs: &T, rhs: &T) -> T {
+        if *lhs == T::MAX || *rhs == T::MAX {
+            T::MAX
+        } else {
+            *lhs + *rhs
+        }
+    }
+}
+/// tropical semiring (max, +).
+/// `T::MIN` is regarded as negative infinity.
+pub struct MaxPlus<T>(std::marker::PhantomData<T>);
+impl<T: Bounded> Semiring for MaxPlus<T> {
+    type S = T;
+    const ZERO: T = T::MIN;
+    const ONE: T = T::ZERO;
+    fn add(lhs: &T, rhs: &T) -> T {
+        if lhs > rhs { *lhs } else { *rhs }
+    }
+    fn mul(lhs: &T, rhs: &T) -> T {
+        if *lhs == T::MIN || *rhs == T::MIN {
+            T::MIN
+        } else {
+            *lhs + *rhs
+        }
+    }
+}
+/// bottleneck semiring (max, min).
+pub struct MaxMin<T>(std::marker::PhantomData<T>);
+impl<T: Bounded> Semiring for MaxMin<T> {
+    type S = T;
+    const ZERO: T = T::MIN;
+    const ONE: T = T::MAX;
+    fn add(lhs: &T, rhs: &T) -> T {
+        if lhs > rhs { *lhs } else { *rhs }
+    }
+    fn mul(lhs: &T, rhs: &T) -> T {
+        if lhs < rhs { *lhs } else { *rhs }
+    }
+}
+/// boolean semiring (or, and).
+pub struct Boolean;
+impl Semiring for Boolean {
+    type S = bool;
+    const ZERO: bool = false;
+    const ONE: bool = true;
+    fn add(lhs: &bool, rhs: &bool) -> bool {
+        *lhs || *rhs
+    }
+    fn mul(lhs: &bool, rhs: &bool) -> bool {
+        *lhs && *rhs
+    }
+}
+/// monoid and group of addition.
+///
+/// # Example
+///
+/// ```
+/// use algebra::{Additive, Group, Monoid};
+/// assert_eq!(Additive::<i64>::op(&3, &4), 7);
+/// assert_eq!(Additive::<i64>::inv(&3), -3);
+/// ```
+///
+pub struct Additive<T>(std::marker::PhantomData<T>);
+impl<T: Semiring<S = T> + Copy> Monoid for Additive<T> {
+    type S = T;
+    const E: T = T::ZERO;
+    fn op(lhs: &T, rhs: &T) -> T {
+        T::add(lhs, rhs)
+    }
+}
+impl<T: Semiring<S = T> + Copy> ComMonoid for Additive<T> {}
+impl<T: Semiring<S = T> + Copy + PartialEq + std::ops::Neg<Output = T>> Group for Additive<T> {
+    fn inv(val: &T) -> T {
+        -*val
+    }
+}
+impl<T: Semiring<S = T> + Copy + PartialEq + std::ops::Neg<Output = T>> Abelian for Additive<T> {}
+/// monoid of minimum.
+/// `T::MAX` is the identity.
+pub struct Min<T>(std::marker::PhantomData<T>);
+impl<T: Bounded> Monoid for Min<T> {
+    type S = T;
+    const E: T = T::MAX;
+    fn op(lhs: &T, rhs: &T) -> T {
+        if lhs < rhs { *lhs } else { *rhs }
+    }
+}
+impl<T: Bounded> ComMonoid for Min<T> {}
+/// monoid of maximum.
+/// `T::MIN` is the identity.
+pub struct Max<T>(std::marker::PhantomData<T>);
+impl<T: Bounded> Monoid for Max<T> {
+    type S = T;
+    const E: T = T::MIN;
+    fn op(lhs: &T, rhs: &T) -> T {
+        if lhs > rhs { *lhs } else { *rhs }
+    }
+}
+impl<T: Bounded> ComMonoid for Max<T> {}
+/// monoid and group of bitwise xor.
+pub struct Xor<T>(std::marker::PhantomData<T>);
+impl<T: Semiring<S = T> + Copy + std::ops::BitXor<Output = T>> Monoid for Xor<T> {
+    type S = T;
+    const E: T = T::ZERO;
+    fn op(lhs: &T, rhs: &T) -> T {
+        *lhs ^ *rhs
+    }
+}
+impl<T: Semiring<S = T> + Copy + std::ops::BitXor<Output = T>> ComMonoid for Xor<T> {}
+impl<T: Semiring<S = T> + Copy + PartialEq + std::ops::BitXor<Output = T>> Group for Xor<T> {
+    fn inv(val: &T) -> T {
+        *val
+    }
+}
+impl<T: Semiring<S = T> + Copy + PartialEq + std::ops::BitXor<Output = T>> Abelian for Xor<T> {}
+/// monoid of the greatest common divisor of non-negative integers.
+/// 0 is the identity.
+///
+/// # Example
+///
+/// ```
+/// use algebra::{Gcd, Monoid};
+/// assert_eq!(<Gcd<u64> as Monoid>::op(&12, &18), 6);
+/// assert_eq!(<Gcd<u64> as Monoid>::op(&0, &5), 5);
+/// ```
+///
+pub struct Gcd<T>(std::marker::PhantomData<T>);
+impl<T: Semiring<S = T> + Copy + PartialEq + std::ops::Rem<Output = T>> Monoid for Gcd<T> {
+    type S = T;
+    const E: T = T::ZERO;
+    fn op(lhs: &T, rhs: &T) -> T {
+        let (mut a, mut b) = (*lhs, *rhs);
+        while b != T::ZERO {
+            (a, b) = (b, a % b);
+        }
+        a
+    }
+}
+impl<T: Semiring<S = T> + Copy + PartialEq + std::ops::Rem<Output = T>> ComMonoid for Gcd<T> {}
+/// monoid of composition of affine maps.
+/// `(a, b)` is the map `x -> a x + b` and `op(f, g)` is the map which applies `f` and then `g`.
+///
+/// # Example
+///
+/// ```
+/// use algebra::{Affine, Monoid};
+/// let f = <Affine<i64> as Monoid>::op(&(2, 1), &(3, 4));
+/// assert_eq!(f, (6, 7));
+/// assert_eq!(Affine::<i64>::apply(&f, 5), 37);
+/// ```
+///
+pub struct Affine<T>(std::marker::PhantomData<T>);
+impl<T: Semiring<S = T> + Copy> Affine<T> {
+    /// Return `a x + b` for `f = (a, b)`.
+    pub fn apply(f: &(T, T), x: T) -> T {
+        T::add(&T::mul(&f.0, &x), &f.1)
+    }
+}
+impl<T: Semiring<S = T> + Copy> Monoid for Affine<T> {
+    type S = (T, T);
+    const E: (T, T) = (T::ONE, T::ZERO);
+    fn op(lhs: &(T, T), rhs: &(T, T)) -> (T, T) {
+        (T::mul(&lhs.0, &rhs.0), T::add(&T::mul(&rhs.0, &lhs.1), &rhs.1))
+    }
+}
+/// monoid of products of `N` x `N` matrices.
+///
+/// # Example
+///
+/// ```
+/// use algebra::{MatMul, Monoid};
+/// let a = [[1, 1], [1, 0]];
+/// let b = <MatMul<u64, 2> as Monoid>::op(&a, &a);
+/// assert_eq!(b, [[2, 1], [1, 1]]);
+/// assert_eq!(<MatMul<u64, 2> as Monoid>::op(&b, &<MatMul<u64, 2> as Monoid>::E), b);
+/// ```
+///
+pub struct MatMul<T, const N: usize>(std::marker::PhantomData<T>);
+impl<T: Semiring<S = T> + Copy, const N: usize> Monoid for MatMul<T, N> {
+    type S = [[T; N]; N];
+    const E: [[T; N]; N] = {
+        let mut res = [[T::ZERO; N]; N];
+        let mut i = 0;
+        while i < N {
+            res[i][i] = T::ONE;
+            i += 1;
+        }
+        res
+    };
+    fn op(lhs: &[[T; N]; N], rhs: &[[T; N]; N]) -> [[T; N]; N] {
+        let mut res = [[T::ZERO; N]; N];
+        for (r, a) in res.iter_mut().zip(lhs) {
+            for (x, b) in a.iter().zip(rhs) {
+                for (y, z) in r.iter_mut().zip(b) {
+                    *y = T::add(y, &T::mul(x, z));
+                }
+            }
+        }
+        res
+    }
+}
+/// product of monoids and groups.
+///
+/// # Example
+///
+/// ```
+/// use algebra::{Additive, Max, Monoid};
+/// type SumMax = (Additive<i64>, Max<i64>);
+/// assert_eq!(SumMax::op(&(3, 5), &(4, -1)), (7, 5));
+/// assert_eq!(SumMax::E, (0, i64::MIN));
+/// ```
+///
+impl<A: Monoid, B: Monoid> Monoid for (A, B) {
+    type S = (A::S, B::S);
+    const E: Self::S = (A::E, B::E);
+    fn op(lhs: &Self::S, rhs: &Self::S) -> Self::S {
+        (A::op(&lhs.0, &rhs.0), B::op(&lhs.1, &rhs.1))
+    }
+}
+impl<A: ComMonoid, B: ComMonoid> ComMonoid for (A, B) {}
+impl<A: Group, B: Group> Group for (A, B) {
+    fn inv(val: &Self::S) -> Self::S {
+        (A::inv(&val.0), B::inv(&val.1))
+    }
+}
+impl<A: Abelian, B: Abelian> Abelian for (A, B) {}
+/// Return `range` as a half-open interval `[l, r)` of indices, where `n` is the end of `..`.
+///
+/// # Example
+///
+/// ```
+/// use algebra::range_to_pair;
+/// assert_eq!(range_to_pair(.., 5), (0, 5));
+/// assert_eq!(range_to_pair(1..=3, 5), (1, 4));
+/// ```
+///
+pub fn range_to_pair(range: impl std::ops::RangeBounds<usize>, n: usize) -> (usize, usize) {
+    use std::ops::Bound::{Excluded, Included, Unbounded};
+    let l = match range.start_bound() {
+        Unbounded => 0,
+        Included(x) => *x,
+        Excluded(x) => x + 1,
+    };
+    let r = match range.end_bound() {
+        Unbounded => n,
+        Included(x) => x + 1,
+        Excluded(x) => *x,
+    };
+    (l, r)
+}
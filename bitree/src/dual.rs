@@ -0,0 +1,73 @@
+use algebra::{Abelian, range_to_pair};
+use std::ops::RangeBounds;
+/// Binary Index Tree with range update and point query over an abelian group.
+///
+/// # Example
+///
+/// ```
+/// use algebra::{Abelian, Group, Monoid};
+/// use bitree::dual::DualBITree;
+/// struct Xor;
+/// impl Monoid for Xor {
+///     type S = u32;
+///     const E: u32 = 0;
+///     fn op(lhs: &u32, rhs: &u32) -> u32 {
+///         lhs ^ rhs
+///     }
+/// }
+/// impl Group for Xor {
+///     fn inv(val: &u32) -> u32 {
+///         *val
+///     }
+/// }
+/// impl Abelian for Xor {}
+/// let mut bit = DualBITree::<Xor>::new(5);
+/// bit.range_op(1..4, &6);
+/// bit.range_op(2.., &3);
+/// assert_eq!(bit.get_at(0), 0);
+/// assert_eq!(bit.get_at(1), 6);
+/// assert_eq!(bit.get_at(3), 5);
+/// assert_eq!(bit.get_at(4), 3);
+/// ```
+///
+pub struct DualBITree<T: Abelian> {
+    n: usize,
+    vals: Vec<T::S>,
+}
+impl<T: Abelian> DualBITree<T> {
+    /// This is initializer of `DualBITree` with all elements `E`.
+    /// This function has a time complexity of O(n).
+    pub fn new(n: usize) -> Self {
+        Self {
+            n,
+            vals: vec![T::E; n + 1],
+        }
+    }
+    /// Update `a_i` to `op(a_i, x)` for `i` in `range`.
+    /// This function has a time complexity of O(log n).
+    pub fn range_op(&mut self, range: impl RangeBounds<usize>, x: &T::S) {
+        let (l, r) = range_to_pair(range, self.n);
+        assert!(l <= r && r <= self.n);
+        self.op_diff(l, x);
+        self.op_diff(r, &T::inv(x));
+    }
+    /// Return `a_i`.
+    /// This function has a time complexity of O(log n).
+    pub fn get_at(&self, i: usize) -> T::S {
+        assert!(i < self.n);
+        let mut res = T::E;
+        let mut k = i + 1;
+        while k > 0 {
+            res = T::op(&res, &self.vals[k]);
+            k -= k & k.wrapping_neg();
+        }
+        res
+    }
+    fn op_diff(&mut self, i: usize, x: &T::S) {
+        let mut k = i + 1;
+        while k <= self.n {
+            self.vals[k] = T::op(&self.vals[k], x);
+            k += k & k.wrapping_neg();
+        }
+    }
+}
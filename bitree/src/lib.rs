@@ -1,4 +1,7 @@
-use algebra::Monoid;
+pub mod dual;
+pub mod range;
+pub mod two_dim;
+use algebra::{Abelian, Monoid, range_to_pair};
 /// Binary Index Tree
 ///
 /// # Example
@@ -47,7 +50,95 @@ impl<T: Monoid> BITree<T> {
         successors(Some(i), |i| Some(i - (i & i.wrapping_neg())))
             .take_while(|i| *i != 0)
             .map(|i| &self.vals[i])
-            .fold(T::E, |acc, a| T::op(&acc, &a))
+            .fold(T::E, |acc, a| T::op(&acc, a))
+    }
+}
+impl<T: Monoid> BITree<T>
+where
+    T::S: PartialOrd,
+{
+    /// Return the minimum `i` such that `prefix_fold(i) >= x`,
+    /// where `prefix_fold` is non-decreasing.
+    /// If there is no such `i`, return `None`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algebra::Monoid;
+    /// use bitree::BITree;
+    /// struct Sum;
+    /// impl Monoid for Sum {
+    ///     type S = u64;
+    ///     const E: u64 = 0;
+    ///     fn op(lhs: &u64, rhs: &u64) -> u64 {
+    ///         lhs + rhs
+    ///     }
+    /// }
+    /// let bit = [3, 0, 2, 5].into_iter().collect::<BITree<Sum>>();
+    /// assert_eq!(bit.lower_bound(&0), Some(0));
+    /// assert_eq!(bit.lower_bound(&3), Some(1));
+    /// assert_eq!(bit.lower_bound(&4), Some(3));
+    /// assert_eq!(bit.lower_bound(&11), None);
+    /// ```
+    ///
+    /// This function has a time complexity of O(log n).
+    pub fn lower_bound(&self, x: &T::S) -> Option<usize> {
+        if *x <= T::E {
+            return Some(0);
+        }
+        let len = self.n - 1;
+        let mut pos = 0;
+        let mut acc = T::E;
+        let mut d = if len == 0 { 0 } else { 1 << len.ilog2() };
+        while d > 0 {
+            if pos + d <= len {
+                let next = T::op(&acc, &self.vals[pos + d]);
+                if next < *x {
+                    pos += d;
+                    acc = next;
+                }
+            }
+            d >>= 1;
+        }
+        (pos < len).then_some(pos + 1)
+    }
+}
+impl<T: Abelian> BITree<T> {
+    /// Return `op(a_l, ..., a_r)` by the inverse of a prefix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use algebra::Additive;
+    /// use bitree::BITree;
+    /// let mut bit = [3, -1, 4, 1, 5].into_iter().collect::<BITree<Additive<i64>>>();
+    /// assert_eq!(bit.range_fold(1..4), 4);
+    /// bit.set_at(2, &10);
+    /// assert_eq!(bit.get_at(2), 10);
+    /// assert_eq!(bit.range_fold(..), 18);
+    /// ```
+    ///
+    /// This function has a time complexity of O(log n).
+    pub fn range_fold(&self, range: impl std::ops::RangeBounds<usize>) -> T::S {
+        let (l, r) = range_to_pair(range, self.n - 1);
+        assert!(l <= r && r < self.n);
+        T::op(&T::inv(&self.prefix_fold(l)), &self.prefix_fold(r))
+    }
+    /// Return `a_i`.
+    /// This function has a time complexity of O(log n).
+    pub fn get_at(&self, i: usize) -> T::S {
+        self.range_fold(i..=i)
+    }
+    /// Update `i` th, from `a_i` to `x`.
+    /// This function has a time complexity of O(log n).
+    pub fn set_at(&mut self, i: usize, x: &T::S) {
+        let d = T::op(&T::inv(&self.get_at(i)), x);
+        self.op_at(i, &d);
+    }
+}
+impl<T: Monoid> Default for BITree<T> {
+    fn default() -> Self {
+        Self::new()
     }
 }
 /// Constructor from iter to `BIT`.
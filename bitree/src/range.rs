@@ -0,0 +1,115 @@
+use algebra::range_to_pair;
+use std::ops::{Add, Mul, RangeBounds, Sub};
+/// Binary Index Tree with range add and range sum by two internal trees.
+///
+/// # Example
+///
+/// ```
+/// use bitree::range::RangeBITree;
+/// let mut bit = [3i64, 1, 4, 1, 5].into_iter().collect::<RangeBITree<_>>();
+/// bit.range_add(1..4, 10);
+/// assert_eq!(bit.range_sum(..), 44);
+/// assert_eq!(bit.range_sum(2..=3), 25);
+/// bit.range_add(.., -1);
+/// assert_eq!(bit.get_at(4), 4);
+/// ```
+///
+pub struct RangeBITree<T> {
+    n: usize,
+    /// `a_i` is `sum_{j <= i} c_j` and `b_j` is `c_j * j` for the differences `c`.
+    a: Vec<T>,
+    b: Vec<T>,
+}
+impl<T> RangeBITree<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + From<i64>,
+{
+    /// This is initializer of `RangeBITree` with all elements 0.
+    /// This function has a time complexity of O(n).
+    pub fn new(n: usize) -> Self {
+        Self {
+            n,
+            a: vec![T::from(0); n + 1],
+            b: vec![T::from(0); n + 1],
+        }
+    }
+    /// Return the length.
+    /// This function has a time complexity of O(1).
+    pub fn len(&self) -> usize {
+        self.n
+    }
+    /// Return true if the length is 0.
+    /// This function has a time complexity of O(1).
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+    /// Update `a_i` to `a_i + x` for `i` in `range`.
+    /// This function has a time complexity of O(log n).
+    pub fn range_add(&mut self, range: impl RangeBounds<usize>, x: T) {
+        let (l, r) = range_to_pair(range, self.n);
+        assert!(l <= r && r <= self.n);
+        self.add_diff(l, x);
+        self.add_diff(r, T::from(0) - x);
+    }
+    /// Return `a_l + ... + a_r`.
+    /// This function has a time complexity of O(log n).
+    pub fn range_sum(&self, range: impl RangeBounds<usize>) -> T {
+        let (l, r) = range_to_pair(range, self.n);
+        assert!(l <= r && r <= self.n);
+        self.prefix_sum(r) - self.prefix_sum(l)
+    }
+    /// Return `a_i`.
+    /// This function has a time complexity of O(log n).
+    pub fn get_at(&self, i: usize) -> T {
+        self.range_sum(i..=i)
+    }
+    /// Return `a_0 + ... + a_{i - 1}`.
+    /// This function has a time complexity of O(log n).
+    pub fn prefix_sum(&self, i: usize) -> T {
+        assert!(i <= self.n);
+        let (mut s, mut t) = (T::from(0), T::from(0));
+        let mut k = i;
+        while k > 0 {
+            s = s + self.a[k];
+            t = t + self.b[k];
+            k -= k & k.wrapping_neg();
+        }
+        s * T::from(i as i64) - t
+    }
+    /// Add `x` to the difference `c_i = a_i - a_{i - 1}`.
+    fn add_diff(&mut self, i: usize, x: T) {
+        let y = x * T::from(i as i64);
+        let mut k = i + 1;
+        while k <= self.n {
+            self.a[k] = self.a[k] + x;
+            self.b[k] = self.b[k] + y;
+            k += k & k.wrapping_neg();
+        }
+    }
+}
+/// Constructor from iter to `RangeBITree`.
+/// This function has a time complexity of O(n).
+impl<T> FromIterator<T> for RangeBITree<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + From<i64>,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let v = iter.into_iter().collect::<Vec<_>>();
+        let n = v.len();
+        let mut res = Self::new(n);
+        let mut prev = T::from(0);
+        for (i, &x) in v.iter().enumerate() {
+            res.a[i + 1] = x - prev;
+            res.b[i + 1] = (x - prev) * T::from(i as i64);
+            prev = x;
+        }
+        for k in 1..=n {
+            let p = k + (k & k.wrapping_neg());
+            if p <= n {
+                res.a[p] = res.a[p] + res.a[k];
+                res.b[p] = res.b[p] + res.b[k];
+            }
+        }
+        res
+    }
+}
@@ -0,0 +1,229 @@
+use algebra::{Abelian, range_to_pair};
+use std::ops::RangeBounds;
+/// Two-dimensional Binary Index Tree over an `h` x `w` grid.
+///
+/// # Example
+///
+/// ```
+/// use algebra::{Abelian, Group, Monoid};
+/// use bitree::two_dim::BITree2D;
+/// struct Sum;
+/// impl Monoid for Sum {
+///     type S = i64;
+///     const E: i64 = 0;
+///     fn op(lhs: &i64, rhs: &i64) -> i64 {
+///         lhs + rhs
+///     }
+/// }
+/// impl Group for Sum {
+///     fn inv(val: &i64) -> i64 {
+///         -val
+///     }
+/// }
+/// impl Abelian for Sum {}
+/// let mut bit = BITree2D::<Sum>::new(3, 4);
+/// bit.op_at(1, 2, &5);
+/// bit.op_at(2, 0, &3);
+/// assert_eq!(bit.range_fold(.., ..), 8);
+/// assert_eq!(bit.range_fold(1..=2, 1..), 5);
+/// assert_eq!(bit.prefix_fold(3, 1), 3);
+/// ```
+///
+pub struct BITree2D<T: Abelian> {
+    h: usize,
+    w: usize,
+    vals: Vec<T::S>,
+}
+impl<T: Abelian> BITree2D<T> {
+    /// This is initializer of `BITree2D` with all elements `E`.
+    /// This function has a time complexity of O(h w).
+    pub fn new(h: usize, w: usize) -> Self {
+        Self {
+            h,
+            w,
+            vals: vec![T::E; (h + 1) * (w + 1)],
+        }
+    }
+    /// Update `(i, j)` th, from `a_ij` to `op(a_ij, x)`.
+    /// This function has a time complexity of O(log h log w).
+    pub fn op_at(&mut self, i: usize, j: usize, x: &T::S) {
+        assert!(i < self.h && j < self.w);
+        let mut i = i + 1;
+        while i <= self.h {
+            let mut j = j + 1;
+            while j <= self.w {
+                let v = &mut self.vals[i * (self.w + 1) + j];
+                *v = T::op(v, x);
+                j += j & j.wrapping_neg();
+            }
+            i += i & i.wrapping_neg();
+        }
+    }
+    /// Return the fold of `a_kl` for `k < i` and `l < j`.
+    /// This function has a time complexity of O(log h log w).
+    pub fn prefix_fold(&self, i: usize, j: usize) -> T::S {
+        assert!(i <= self.h && j <= self.w);
+        let mut res = T::E;
+        let mut i = i;
+        while i > 0 {
+            let mut j = j;
+            while j > 0 {
+                res = T::op(&res, &self.vals[i * (self.w + 1) + j]);
+                j -= j & j.wrapping_neg();
+            }
+            i -= i & i.wrapping_neg();
+        }
+        res
+    }
+    /// Return the fold of `a_ij` for `i` in `rows` and `j` in `cols`.
+    /// This function has a time complexity of O(log h log w).
+    pub fn range_fold(&self, rows: impl RangeBounds<usize>, cols: impl RangeBounds<usize>) -> T::S {
+        let (l, r) = range_to_pair(rows, self.h);
+        let (cl, cr) = range_to_pair(cols, self.w);
+        assert!(l <= r && cl <= cr);
+        let pos = T::op(&self.prefix_fold(r, cr), &self.prefix_fold(l, cl));
+        let neg = T::op(&self.prefix_fold(l, cr), &self.prefix_fold(r, cl));
+        T::op(&pos, &T::inv(&neg))
+    }
+}
+/// Segment tree over sorted x coordinates with inner Binary Index Trees over y coordinates.
+/// Points must be registered at initialization.
+///
+/// # Example
+///
+/// ```
+/// use algebra::{Abelian, Group, Monoid};
+/// use bitree::two_dim::SparseBITree2D;
+/// struct Sum;
+/// impl Monoid for Sum {
+///     type S = i64;
+///     const E: i64 = 0;
+///     fn op(lhs: &i64, rhs: &i64) -> i64 {
+///         lhs + rhs
+///     }
+/// }
+/// impl Group for Sum {
+///     fn inv(val: &i64) -> i64 {
+///         -val
+///     }
+/// }
+/// impl Abelian for Sum {}
+/// let points = [(1_000_000_000, -5), (3, 7), (-2, 7)];
+/// let mut bit = SparseBITree2D::<Sum>::new(&points);
+/// bit.op_at(3, 7, &10);
+/// bit.op_at(-2, 7, &1);
+/// bit.op_at(1_000_000_000, -5, &100);
+/// assert_eq!(bit.range_fold(.., ..), 111);
+/// assert_eq!(bit.range_fold(0.., 0..=7), 10);
+/// assert_eq!(bit.range_fold(-2..4, 7..), 11);
+/// ```
+///
+pub struct SparseBITree2D<T: Abelian> {
+    m: usize,
+    xs: Vec<i64>,
+    /// `ys[k]` is the sorted y coordinates of points under node `k`.
+    ys: Vec<Vec<i64>>,
+    vals: Vec<Vec<T::S>>,
+}
+impl<T: Abelian> SparseBITree2D<T> {
+    /// This is initializer of `SparseBITree2D` with all elements `E` on `points`.
+    /// This function has a time complexity of O(n log n).
+    pub fn new(points: &[(i64, i64)]) -> Self {
+        let mut points = points.to_vec();
+        points.sort_unstable();
+        points.dedup();
+        let mut xs = points.iter().map(|p| p.0).collect::<Vec<_>>();
+        xs.dedup();
+        let m = xs.len().next_power_of_two();
+        let mut ys = vec![vec![]; 2 * m];
+        for &(x, y) in &points {
+            ys[m + xs.partition_point(|v| *v < x)].push(y);
+        }
+        for k in (1..m).rev() {
+            let (a, b) = (&ys[2 * k], &ys[2 * k + 1]);
+            let mut merged = Vec::with_capacity(a.len() + b.len());
+            let (mut i, mut j) = (0, 0);
+            while i < a.len() || j < b.len() {
+                if j == b.len() || (i < a.len() && a[i] <= b[j]) {
+                    merged.push(a[i]);
+                    i += 1;
+                } else {
+                    merged.push(b[j]);
+                    j += 1;
+                }
+            }
+            merged.dedup();
+            ys[k] = merged;
+        }
+        let vals = ys.iter().map(|y| vec![T::E; y.len() + 1]).collect();
+        Self { m, xs, ys, vals }
+    }
+    /// Update the element at `(x, y)` from `a` to `op(a, v)`, where `(x, y)` is registered.
+    /// This function has a time complexity of O(log^2 n).
+    pub fn op_at(&mut self, x: i64, y: i64, v: &T::S) {
+        let i = self.xs.binary_search(&x).expect("(x, y) is not registered");
+        let mut k = i + self.m;
+        while k > 0 {
+            let ys = &self.ys[k];
+            let mut j = ys.binary_search(&y).expect("(x, y) is not registered") + 1;
+            let vals = &mut self.vals[k];
+            while j < vals.len() {
+                vals[j] = T::op(&vals[j], v);
+                j += j & j.wrapping_neg();
+            }
+            k >>= 1;
+        }
+    }
+    /// Return the fold of the elements at `(x, y)` for `x` in `xr` and `y` in `yr`.
+    /// This function has a time complexity of O(log^2 n).
+    pub fn range_fold(&self, xr: impl RangeBounds<i64>, yr: impl RangeBounds<i64>) -> T::S {
+        let (l, r) = index_range(&self.xs, &xr);
+        let (mut l, mut r) = (l + self.m, r + self.m);
+        let mut res = T::E;
+        while l < r {
+            if l & 1 == 1 {
+                res = T::op(&res, &self.node_fold(l, &yr));
+                l += 1;
+            }
+            if r & 1 == 1 {
+                r -= 1;
+                res = T::op(&res, &self.node_fold(r, &yr));
+            }
+            l >>= 1;
+            r >>= 1;
+        }
+        res
+    }
+    /// Return the fold of the elements in node `k` with `y` in `yr`.
+    /// This function has a time complexity of O(log n).
+    fn node_fold(&self, k: usize, yr: &impl RangeBounds<i64>) -> T::S {
+        let (l, r) = index_range(&self.ys[k], yr);
+        if l >= r {
+            return T::E;
+        }
+        let prefix = |mut i: usize| {
+            let mut res = T::E;
+            while i > 0 {
+                res = T::op(&res, &self.vals[k][i]);
+                i -= i & i.wrapping_neg();
+            }
+            res
+        };
+        T::op(&prefix(r), &T::inv(&prefix(l)))
+    }
+}
+/// Return the index range of sorted `a` whose values are in `range`.
+fn index_range(a: &[i64], range: &impl RangeBounds<i64>) -> (usize, usize) {
+    use std::ops::Bound::{Excluded, Included, Unbounded};
+    let l = match range.start_bound() {
+        Unbounded => 0,
+        Included(x) => a.partition_point(|v| v < x),
+        Excluded(x) => a.partition_point(|v| v <= x),
+    };
+    let r = match range.end_bound() {
+        Unbounded => a.len(),
+        Included(x) => a.partition_point(|v| v <= x),
+        Excluded(x) => a.partition_point(|v| v < x),
+    };
+    (l, r)
+}
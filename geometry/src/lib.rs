@@ -29,6 +29,21 @@ impl Vector {
     pub fn norm_pow2(&self) -> i64 {
         self.x() * self.x() + self.y() * self.y()
     }
+    /// Return the sign of `(a - self) x (b - self)` compared with 0.
+    /// It is calculated without overflow for any coordinates.
+    /// This function has a time complexity of O(1).
+    pub fn ccw(&self, a: &Self, b: &Self) -> std::cmp::Ordering {
+        let (ax, ay) = (a.x() as i128 - self.x() as i128, a.y() as i128 - self.y() as i128);
+        let (bx, by) = (b.x() as i128 - self.x() as i128, b.y() as i128 - self.y() as i128);
+        // Each factor is less than 2^64 in absolute value, so each product fits in u128.
+        let key = |p: i128, q: i128| (p.signum() * q.signum(), p.unsigned_abs() * q.unsigned_abs());
+        let ((s, l), (t, r)) = (key(ax, by), key(ay, bx));
+        match s.cmp(&t) {
+            std::cmp::Ordering::Equal if s > 0 => l.cmp(&r),
+            std::cmp::Ordering::Equal if s < 0 => r.cmp(&l),
+            ord => ord,
+        }
+    }
 }
 impl From<(i64, i64)> for Vector {
     fn from(t: (i64, i64)) -> Self {
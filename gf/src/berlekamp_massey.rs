@@ -0,0 +1,45 @@
+use super::GF;
+/// Return the shortest linear recurrence `c` of `s`,
+/// that is `s_i = c_0 s_{i - 1} + ... + c_{l - 1} s_{i - l}` for all `i >= l`.
+/// This function has a time complexity of O(n^2).
+pub fn berlekamp_massey<const MOD: u32>(s: &[GF<MOD>]) -> Vec<GF<MOD>> {
+    let zero = GF::<MOD>::new(0);
+    let mut cur = Vec::new();
+    let mut prev = Vec::new();
+    let mut last = 0;
+    let mut last_d = zero;
+    for i in 0..s.len() {
+        let d = s[i]
+            - cur
+                .iter()
+                .zip(s[..i].iter().rev())
+                .map(|(c, s)| c * s)
+                .sum::<GF<MOD>>();
+        if d == zero {
+            continue;
+        }
+        if cur.is_empty() {
+            cur = vec![zero; i + 1];
+            last = i;
+            last_d = d;
+            continue;
+        }
+        let k = d / last_d;
+        let mut next = vec![zero; i - last - 1];
+        next.push(k);
+        next.extend(prev.iter().map(|p| -(k * p)));
+        if next.len() < cur.len() {
+            next.resize(cur.len(), zero);
+        }
+        for (n, c) in next.iter_mut().zip(cur.iter()) {
+            *n += c;
+        }
+        if i - last + prev.len() >= cur.len() {
+            prev = cur;
+            last = i;
+            last_d = d;
+        }
+        cur = next;
+    }
+    cur
+}
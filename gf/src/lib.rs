@@ -1,3 +1,4 @@
+pub mod berlekamp_massey;
 pub mod combinatorics;
 /// galois field Z/MOD Z.
 ///
@@ -42,6 +43,29 @@ impl<const MOD: u32> GF<MOD> {
         self.val
     }
 }
+/// `GF` as a ring for generic monoids such as `algebra::Affine`.
+///
+/// # Example
+///
+/// ```
+/// use algebra::{Affine, Monoid};
+/// use gf::GF;
+/// type F = GF<998244353>;
+/// let f = <Affine<F> as Monoid>::op(&(F::new(2), F::new(3)), &(F::new(4), F::new(5)));
+/// assert_eq!(Affine::apply(&f, F::new(1)), F::new(25));
+/// ```
+///
+impl<const MOD: u32> algebra::Semiring for GF<MOD> {
+    type S = Self;
+    const ZERO: Self = Self::new(0);
+    const ONE: Self = Self::new(1);
+    fn add(lhs: &Self, rhs: &Self) -> Self {
+        *lhs + *rhs
+    }
+    fn mul(lhs: &Self, rhs: &Self) -> Self {
+        *lhs * *rhs
+    }
+}
 use std::fmt::{Debug, Display, Formatter, Result};
 impl<const MOD: u32> Debug for GF<MOD> {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
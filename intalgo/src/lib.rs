@@ -1,5 +1,6 @@
 pub mod eratos;
 pub mod prime;
+pub mod rational;
 /// Return all divisors.
 /// This function has a time complexity of O(sqrt n).
 pub fn divisors(n: u64) -> Vec<u64> {
@@ -16,7 +17,6 @@ pub fn divisors(n: u64) -> Vec<u64> {
     prefix.extend(suffix.iter().rev());
     prefix
 }
-aaa
 /// Return a ^ n mod m.
 /// This function has a time complexity of O(log n).
 pub fn pow_mod<T: std::ops::Mul<Output = T> + std::ops::Rem<Output = T> + From<u8> + Copy>(
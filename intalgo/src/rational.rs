@@ -0,0 +1,193 @@
+/// rational number num / den.
+///
+/// # Example
+///
+/// ```
+/// use intalgo::rational::Rational;
+/// let a = Rational::new(1, 2) + Rational::new(1, 3);
+/// assert_eq!(a, Rational::new(5, 6));
+/// assert_eq!(Rational::new(-7, 2).floor(), -4);
+/// ```
+///
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    num: i128,
+    den: i128,
+}
+impl Rational {
+    /// This is initializer of `Rational`.
+    /// This function has a time complexity of O(log max(num, den)).
+    pub fn new(num: i128, den: i128) -> Self {
+        assert_ne!(den, 0);
+        let g = gcd(num, den);
+        let (num, den) = (num / g, den / g);
+        if den < 0 {
+            Self {
+                num: -num,
+                den: -den,
+            }
+        } else {
+            Self { num, den }
+        }
+    }
+    /// Return the numerator.
+    /// This function has a time complexity of O(1).
+    pub fn num(&self) -> i128 {
+        self.num
+    }
+    /// Return the denominator, which is positive.
+    /// This function has a time complexity of O(1).
+    pub fn den(&self) -> i128 {
+        self.den
+    }
+    /// Return the greatest integer less than or equal to `self`.
+    /// This function has a time complexity of O(1).
+    pub fn floor(&self) -> i128 {
+        self.num.div_euclid(self.den)
+    }
+    /// Return the least integer greater than or equal to `self`.
+    /// This function has a time complexity of O(1).
+    pub fn ceil(&self) -> i128 {
+        -(-self.num).div_euclid(self.den)
+    }
+    /// Return `|self|`.
+    /// This function has a time complexity of O(1).
+    pub fn abs(&self) -> Self {
+        Self {
+            num: self.num.abs(),
+            den: self.den,
+        }
+    }
+}
+fn gcd(mut a: i128, mut b: i128) -> i128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    if a == 0 { 1 } else { a.abs() }
+}
+use std::fmt::{Debug, Display, Formatter, Result};
+impl Debug for Rational {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}/{}", self.num, self.den)
+    }
+}
+impl Display for Rational {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        if self.den == 1 {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+use std::cmp::Ordering;
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.num * other.den).cmp(&(other.num * self.den))
+    }
+}
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+impl Neg for Rational {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self {
+            num: -self.num,
+            den: self.den,
+        }
+    }
+}
+impl AddAssign<Rational> for Rational {
+    fn add_assign(&mut self, rhs: Rational) {
+        let g = gcd(self.den, rhs.den);
+        *self = Self::new(
+            self.num * (rhs.den / g) + rhs.num * (self.den / g),
+            self.den / g * rhs.den,
+        );
+    }
+}
+impl SubAssign<Rational> for Rational {
+    fn sub_assign(&mut self, rhs: Rational) {
+        *self += -rhs;
+    }
+}
+impl MulAssign<Rational> for Rational {
+    fn mul_assign(&mut self, rhs: Rational) {
+        let g = gcd(self.num, rhs.den);
+        let h = gcd(rhs.num, self.den);
+        *self = Self::new(
+            (self.num / g) * (rhs.num / h),
+            (self.den / h) * (rhs.den / g),
+        );
+    }
+}
+impl DivAssign<Rational> for Rational {
+    fn div_assign(&mut self, rhs: Rational) {
+        assert_ne!(rhs.num, 0);
+        *self *= Self {
+            num: rhs.den,
+            den: rhs.num,
+        };
+    }
+}
+macro_rules! rational_ops {
+    ($(
+            $trait:ident,
+            $trait_assign:ident,
+            $fn:ident,
+            $fn_assign:ident,
+    )*) => {$(
+        impl $trait_assign<&Rational> for Rational {
+            fn $fn_assign(&mut self, rhs: &Rational) {
+                self.$fn_assign(*rhs);
+            }
+        }
+        impl<T: Into<Rational>> $trait<T> for Rational {
+            type Output = Rational;
+            fn $fn(mut self, rhs: T) -> Self::Output {
+                self.$fn_assign(rhs.into());
+                self
+            }
+        }
+        impl $trait<&Rational> for Rational {
+            type Output = Rational;
+            fn $fn(self, rhs: &Rational) -> Self::Output {
+                self.$fn(*rhs)
+            }
+        }
+        impl<T: Into<Rational>> $trait<T> for &Rational {
+            type Output = Rational;
+            fn $fn(self, rhs: T) -> Self::Output {
+                (*self).$fn(rhs.into())
+            }
+        }
+        impl $trait<&Rational> for &Rational {
+            type Output = Rational;
+            fn $fn(self, rhs: &Rational) -> Self::Output {
+                (*self).$fn(*rhs)
+            }
+        }
+    )*};
+}
+rational_ops! {
+    Add, AddAssign, add, add_assign,
+    Sub, SubAssign, sub, sub_assign,
+    Mul, MulAssign, mul, mul_assign,
+    Div, DivAssign, div, div_assign,
+}
+macro_rules! rational_from {
+    ($($t:ty), *) => {
+        $(
+            impl From<$t> for Rational {
+                fn from(x: $t) -> Self {
+                    Self { num: x as i128, den: 1 }
+                }
+            }
+        )*
+    };
+}
+rational_from!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
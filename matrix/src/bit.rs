@@ -0,0 +1,308 @@
+/// Matrix over GF(2) packed into `u64` words.
+///
+/// # Example
+///
+/// ```
+/// use matrix::bit::BitMatrix;
+/// let a = BitMatrix::new(2, 2, &[vec![true, true], vec![false, true]]);
+/// assert!(a.determinant());
+/// let b = a.inverse().unwrap();
+/// assert_eq!(a.mul(&b), BitMatrix::e(2));
+/// ```
+///
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct BitMatrix {
+    h: usize,
+    w: usize,
+    words: usize,
+    val: Box<[u64]>,
+}
+impl BitMatrix {
+    /// This is initializer of `BitMatrix`.
+    /// This function has a time complexity of O(h w).
+    pub fn new(h: usize, w: usize, a: &[Vec<bool>]) -> Self {
+        let mut res = Self::zeros(h, w);
+        for (i, a) in a.iter().enumerate() {
+            for (j, a) in a.iter().enumerate() {
+                res.set(i, j, *a);
+            }
+        }
+        res
+    }
+    /// Return the zero matrix of size `h` x `w`.
+    /// This function has a time complexity of O(h w / 64).
+    pub fn zeros(h: usize, w: usize) -> Self {
+        let words = w.div_ceil(64);
+        Self {
+            h,
+            w,
+            words,
+            val: vec![0; h * words].into_boxed_slice(),
+        }
+    }
+    /// Return the identity matrix of size `n`.
+    /// This function has a time complexity of O(n^2 / 64).
+    pub fn e(n: usize) -> Self {
+        let mut res = Self::zeros(n, n);
+        for i in 0..n {
+            res.set(i, i, true);
+        }
+        res
+    }
+    /// Return the element at `(i, j)`.
+    /// This function has a time complexity of O(1).
+    pub fn get(&self, i: usize, j: usize) -> bool {
+        assert!(i < self.h && j < self.w);
+        self.val[i * self.words + j / 64] >> (j % 64) & 1 == 1
+    }
+    /// Update the element at `(i, j)` to `x`.
+    /// This function has a time complexity of O(1).
+    pub fn set(&mut self, i: usize, j: usize, x: bool) {
+        assert!(i < self.h && j < self.w);
+        let v = &mut self.val[i * self.words + j / 64];
+        if x {
+            *v |= 1 << (j % 64);
+        } else {
+            *v &= !(1 << (j % 64));
+        }
+    }
+    /// Return the packed words of row `i`.
+    /// This function has a time complexity of O(1).
+    pub fn row(&self, i: usize) -> &[u64] {
+        &self.val[i * self.words..(i + 1) * self.words]
+    }
+    /// Return the product of `self` and `other` by the method of Four Russians.
+    /// This function has a time complexity of O(h w l / 512).
+    pub fn mul(&self, other: &Self) -> Self {
+        assert_eq!(self.w, other.h);
+        let words = other.words;
+        let mut res = Self::zeros(self.h, other.w);
+        let mut table = vec![0u64; 256 * words];
+        for k in (0..self.w).step_by(8) {
+            let t = (self.w - k).min(8);
+            for s in 1..1usize << t {
+                let b = s.trailing_zeros() as usize;
+                let (prev, cur) = table.split_at_mut(s * words);
+                let prev = &prev[(s & (s - 1)) * words..];
+                for ((c, p), o) in cur[..words]
+                    .iter_mut()
+                    .zip(prev.iter())
+                    .zip(other.row(k + b).iter())
+                {
+                    *c = p ^ o;
+                }
+            }
+            for (i, r) in res.val.chunks_exact_mut(words.max(1)).enumerate() {
+                let s = (self.val[i * self.words + k / 64] >> (k % 64)) as usize & ((1 << t) - 1);
+                if s != 0 {
+                    for (r, t) in r.iter_mut().zip(table[s * words..].iter()) {
+                        *r ^= t;
+                    }
+                }
+            }
+        }
+        res
+    }
+    /// Transform `self` into reduced row echelon form, and return its rank.
+    /// This function has a time complexity of O(h w min(h, w) / 64).
+    pub fn gaussian_elimination(&mut self) -> usize {
+        self.pivots().len()
+    }
+    /// Return the rank of `self`.
+    /// This function has a time complexity of O(h w min(h, w) / 64).
+    pub fn rank(&self) -> usize {
+        self.clone().gaussian_elimination()
+    }
+    /// Return the determinant of `self`.
+    /// This function has a time complexity of O(n^3 / 64).
+    pub fn determinant(&self) -> bool {
+        assert_eq!(self.h, self.w);
+        self.rank() == self.h
+    }
+    /// Return a solution `x` of `self * x = b` and a basis of the kernel of `self`.
+    /// If there is no solution, return `None`.
+    /// This function has a time complexity of O(h w min(h, w) / 64 + w^2).
+    pub fn solve(&self, b: &[bool]) -> Option<(Vec<bool>, Vec<Vec<bool>>)> {
+        assert_eq!(self.h, b.len());
+        let (h, w) = (self.h, self.w);
+        let mut a = Self::zeros(h, w + 1);
+        for (i, b) in b.iter().enumerate() {
+            a.val[i * a.words..i * a.words + self.words].copy_from_slice(self.row(i));
+            a.set(i, w, *b);
+        }
+        let pivots = a.pivots();
+        if pivots.last() == Some(&w) {
+            return None;
+        }
+        let mut x = vec![false; w];
+        for (i, &p) in pivots.iter().enumerate() {
+            x[p] = a.get(i, w);
+        }
+        let mut is_pivot = vec![false; w];
+        for &p in &pivots {
+            is_pivot[p] = true;
+        }
+        let kernel = (0..w)
+            .filter(|f| !is_pivot[*f])
+            .map(|f| {
+                let mut v = vec![false; w];
+                v[f] = true;
+                for (i, &p) in pivots.iter().enumerate() {
+                    v[p] = a.get(i, f);
+                }
+                v
+            })
+            .collect();
+        Some((x, kernel))
+    }
+    /// Return the inverse matrix of `self`.
+    /// If `self` is singular, return `None`.
+    /// This function has a time complexity of O(n^3 / 64).
+    pub fn inverse(&self) -> Option<Self> {
+        assert_eq!(self.h, self.w);
+        let n = self.h;
+        let mut a = Self::zeros(n, 2 * n);
+        for i in 0..n {
+            for j in 0..n {
+                a.set(i, j, self.get(i, j));
+            }
+            a.set(i, n + i, true);
+        }
+        let pivots = a.pivots();
+        if pivots.len() < n || pivots.last().is_some_and(|&p| p >= n) {
+            return None;
+        }
+        let mut res = Self::zeros(n, n);
+        for i in 0..n {
+            for j in 0..n {
+                res.set(i, j, a.get(i, n + j));
+            }
+        }
+        Some(res)
+    }
+    /// Transform `self` into reduced row echelon form, and return its pivot columns.
+    /// This function has a time complexity of O(h w min(h, w) / 64).
+    fn pivots(&mut self) -> Vec<usize> {
+        let (h, w, words) = (self.h, self.w, self.words);
+        let mut pivots = Vec::with_capacity(h.min(w));
+        for y in 0..w {
+            let x = pivots.len();
+            if x == h {
+                break;
+            }
+            let (q, b) = (y / 64, y % 64);
+            if let Some(k) = (x..h).find(|k| self.val[k * words + q] >> b & 1 == 1) {
+                for j in q..words {
+                    self.val.swap(x * words + j, k * words + j);
+                }
+                let (upper, lower) = self.val.split_at_mut(x * words);
+                let (pivot, lower) = lower.split_at_mut(words);
+                for r in upper
+                    .chunks_exact_mut(words)
+                    .chain(lower.chunks_exact_mut(words))
+                {
+                    if r[q] >> b & 1 == 1 {
+                        for (r, p) in r[q..].iter_mut().zip(pivot[q..].iter()) {
+                            *r ^= p;
+                        }
+                    }
+                }
+                pivots.push(y);
+            }
+        }
+        pivots
+    }
+}
+/// Linear basis of `u64` over GF(2).
+///
+/// # Example
+///
+/// ```
+/// use matrix::bit::XorBasis;
+/// let mut basis = XorBasis::new();
+/// assert!(basis.insert(0b101));
+/// assert!(basis.insert(0b011));
+/// assert!(!basis.insert(0b110));
+/// assert_eq!(basis.max_xor(0), 0b110);
+/// assert_eq!(basis.kth(1), Some(0b011));
+/// ```
+///
+#[derive(Clone)]
+pub struct XorBasis {
+    basis: [u64; 64],
+    rank: usize,
+}
+impl XorBasis {
+    /// This is initializer of `XorBasis` spanning `{0}`.
+    /// This function has a time complexity of O(1).
+    pub fn new() -> Self {
+        Self {
+            basis: [0; 64],
+            rank: 0,
+        }
+    }
+    /// Return the dimension of the span.
+    /// This function has a time complexity of O(1).
+    pub fn rank(&self) -> usize {
+        self.rank
+    }
+    /// Insert `x` into the span.
+    /// If `x` is already in the span return false, otherwise return true.
+    /// This function has a time complexity of O(64).
+    pub fn insert(&mut self, mut x: u64) -> bool {
+        while x != 0 {
+            let b = 63 - x.leading_zeros() as usize;
+            if self.basis[b] == 0 {
+                self.basis[b] = x;
+                self.rank += 1;
+                return true;
+            }
+            x ^= self.basis[b];
+        }
+        false
+    }
+    /// Return whether `x` is in the span.
+    /// This function has a time complexity of O(64).
+    pub fn contains(&self, x: u64) -> bool {
+        self.min_xor(x) == 0
+    }
+    /// Return the maximum of `x ^ y` for `y` in the span.
+    /// This function has a time complexity of O(64).
+    pub fn max_xor(&self, x: u64) -> u64 {
+        self.basis.iter().rev().fold(x, |x, b| x.max(x ^ b))
+    }
+    /// Return the minimum of `x ^ y` for `y` in the span.
+    /// This function has a time complexity of O(64).
+    pub fn min_xor(&self, x: u64) -> u64 {
+        self.basis.iter().rev().fold(x, |x, b| x.min(x ^ b))
+    }
+    /// Return the `k` th (0-indexed) smallest element of the span, which contains 0.
+    /// If the span has at most `k` elements, return `None`.
+    /// This function has a time complexity of O(64^2).
+    pub fn kth(&self, k: u64) -> Option<u64> {
+        if self.rank < 64 && k >> self.rank != 0 {
+            return None;
+        }
+        let mut basis = self.basis;
+        for i in 0..64 {
+            for j in i + 1..64 {
+                if basis[j] >> i & 1 == 1 {
+                    basis[j] ^= basis[i];
+                }
+            }
+        }
+        Some(
+            basis
+                .iter()
+                .filter(|b| **b != 0)
+                .enumerate()
+                .filter(|(i, _)| k >> i & 1 == 1)
+                .fold(0, |acc, (_, b)| acc ^ b),
+        )
+    }
+}
+impl Default for XorBasis {
+    fn default() -> Self {
+        Self::new()
+    }
+}
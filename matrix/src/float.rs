@@ -0,0 +1,210 @@
+use super::Matrix;
+/// Relative tolerance of pivots.
+/// A pivot is regarded as zero if its absolute value is at most `EPS` times
+/// the maximum absolute value of the entries of the decomposed matrix.
+pub const EPS: f64 = 1e-9;
+/// LU decomposition with partial pivoting, `P A = L U`.
+///
+/// # Example
+///
+/// ```
+/// use matrix::Matrix;
+/// let a = Matrix::new(2, 2, &[vec![2.0, 1.0], vec![4.0, 3.0]]);
+/// let lu = a.lu();
+/// assert!((lu.determinant() - 2.0).abs() < 1e-9);
+/// let x = lu.solve(&[3.0, 7.0]).unwrap();
+/// assert!((x[0] - 1.0).abs() < 1e-9 && (x[1] - 1.0).abs() < 1e-9);
+/// let tiny = Matrix::new(2, 2, &[vec![2e-10, 1e-10], vec![4e-10, 3e-10]]);
+/// assert!(!tiny.lu().is_singular());
+/// ```
+///
+pub struct LU {
+    n: usize,
+    lu: Matrix<f64>,
+    perm: Vec<usize>,
+    sign: f64,
+    singular: bool,
+}
+/// QR decomposition by Householder reflections, `A = Q R`.
+///
+/// # Example
+///
+/// ```
+/// use matrix::Matrix;
+/// let a = Matrix::new(4, 2, &[vec![1.0, 0.0], vec![1.0, 1.0], vec![1.0, 2.0], vec![1.0, 3.0]]);
+/// let qr = a.qr();
+/// let x = qr.least_squares(&[1.0, 3.0, 5.0, 7.0]).unwrap();
+/// assert!((x[0] - 1.0).abs() < 1e-9 && (x[1] - 2.0).abs() < 1e-9);
+/// let tiny = Matrix::new(2, 2, &[vec![2e-10, 1e-10], vec![4e-10, 3e-10]]);
+/// assert!(tiny.qr().least_squares(&[3e-10, 7e-10]).is_some());
+/// ```
+///
+pub struct QR {
+    h: usize,
+    w: usize,
+    tol: f64,
+    r: Matrix<f64>,
+    vs: Vec<Vec<f64>>,
+}
+impl Matrix<f64> {
+    /// Return the LU decomposition of `self`.
+    /// This function has a time complexity of O(n^3).
+    pub fn lu(&self) -> LU {
+        assert_eq!(self.h, self.w);
+        let n = self.h;
+        let mut lu = self.clone();
+        let mut perm = (0..n).collect::<Vec<_>>();
+        let mut sign = 1.0;
+        let mut singular = false;
+        let tol = EPS * self.max_abs();
+        for k in 0..n {
+            let p = (k..n)
+                .max_by(|&i, &j| lu[i][k].abs().total_cmp(&lu[j][k].abs()))
+                .unwrap();
+            if lu[p][k].abs() <= tol {
+                singular = true;
+                continue;
+            }
+            if p != k {
+                for j in 0..n {
+                    lu.val.swap(p * n + j, k * n + j);
+                }
+                perm.swap(p, k);
+                sign = -sign;
+            }
+            let (upper, lower) = lu.val.split_at_mut((k + 1) * n);
+            let pivot = &upper[k * n..];
+            for r in lower.chunks_exact_mut(n) {
+                let f = r[k] / pivot[k];
+                r[k] = f;
+                for (v, p) in r[k + 1..].iter_mut().zip(pivot[k + 1..].iter()) {
+                    *v -= f * p;
+                }
+            }
+        }
+        LU {
+            n,
+            lu,
+            perm,
+            sign,
+            singular,
+        }
+    }
+    /// Return the QR decomposition of `self`.
+    /// `self` must have at least as many rows as columns.
+    /// This function has a time complexity of O(h w^2).
+    pub fn qr(&self) -> QR {
+        assert!(self.h >= self.w);
+        let (h, w) = (self.h, self.w);
+        let mut r = self.clone();
+        let mut vs = Vec::with_capacity(w);
+        let tol = EPS * self.max_abs();
+        for k in 0..w {
+            let norm = (k..h).map(|i| r[i][k] * r[i][k]).sum::<f64>().sqrt();
+            let alpha = if r[k][k] > 0.0 { -norm } else { norm };
+            let mut v = (k..h).map(|i| r[i][k]).collect::<Vec<_>>();
+            v[0] -= alpha;
+            let vv = v.iter().map(|v| v * v).sum::<f64>();
+            if vv > tol * tol {
+                for j in k..w {
+                    let f = 2.0 * (k..h).map(|i| v[i - k] * r[i][j]).sum::<f64>() / vv;
+                    for i in k..h {
+                        r[i][j] -= f * v[i - k];
+                    }
+                }
+            }
+            vs.push(v);
+        }
+        QR { h, w, tol, r, vs }
+    }
+    /// Return the maximum absolute value of the entries.
+    /// This function has a time complexity of O(h w).
+    fn max_abs(&self) -> f64 {
+        self.val.iter().fold(0.0, |acc, v| acc.max(v.abs()))
+    }
+}
+impl LU {
+    /// Return whether the decomposed matrix is singular.
+    /// This function has a time complexity of O(1).
+    pub fn is_singular(&self) -> bool {
+        self.singular
+    }
+    /// Return the determinant of the decomposed matrix.
+    /// This function has a time complexity of O(n).
+    pub fn determinant(&self) -> f64 {
+        if self.singular {
+            0.0
+        } else {
+            (0..self.n).fold(self.sign, |acc, i| acc * self.lu[i][i])
+        }
+    }
+    /// Return `x` such that `A x = b`.
+    /// If `A` is singular, return `None`.
+    /// This function has a time complexity of O(n^2).
+    pub fn solve(&self, b: &[f64]) -> Option<Vec<f64>> {
+        assert_eq!(self.n, b.len());
+        if self.singular {
+            return None;
+        }
+        let n = self.n;
+        let mut x = self.perm.iter().map(|&i| b[i]).collect::<Vec<_>>();
+        for i in 0..n {
+            let s = (0..i).map(|j| self.lu[i][j] * x[j]).sum::<f64>();
+            x[i] -= s;
+        }
+        for i in (0..n).rev() {
+            let s = (i + 1..n).map(|j| self.lu[i][j] * x[j]).sum::<f64>();
+            x[i] = (x[i] - s) / self.lu[i][i];
+        }
+        Some(x)
+    }
+    /// Return the inverse of the decomposed matrix.
+    /// If `A` is singular, return `None`.
+    /// This function has a time complexity of O(n^3).
+    pub fn inverse(&self) -> Option<Matrix<f64>> {
+        let n = self.n;
+        let mut res = Matrix {
+            h: n,
+            w: n,
+            val: vec![0.0; n * n].into_boxed_slice(),
+        };
+        let mut e = vec![0.0; n];
+        for j in 0..n {
+            e[j] = 1.0;
+            let x = self.solve(&e)?;
+            e[j] = 0.0;
+            for (r, x) in res.iter_mut().zip(x.iter()) {
+                r[j] = *x;
+            }
+        }
+        Some(res)
+    }
+}
+impl QR {
+    /// Return `x` which minimizes `|A x - b|`.
+    /// If `A` does not have full column rank, return `None`.
+    /// This function has a time complexity of O(h w).
+    pub fn least_squares(&self, b: &[f64]) -> Option<Vec<f64>> {
+        assert_eq!(self.h, b.len());
+        let (h, w) = (self.h, self.w);
+        let mut b = b.to_vec();
+        for (k, v) in self.vs.iter().enumerate() {
+            let vv = v.iter().map(|v| v * v).sum::<f64>();
+            if vv > self.tol * self.tol {
+                let f = 2.0 * (k..h).map(|i| v[i - k] * b[i]).sum::<f64>() / vv;
+                for i in k..h {
+                    b[i] -= f * v[i - k];
+                }
+            }
+        }
+        let mut x = vec![0.0; w];
+        for i in (0..w).rev() {
+            if self.r[i][i].abs() <= self.tol {
+                return None;
+            }
+            let s = (i + 1..w).map(|j| self.r[i][j] * x[j]).sum::<f64>();
+            x[i] = (b[i] - s) / self.r[i][i];
+        }
+        Some(x)
+    }
+}
@@ -1,4 +1,9 @@
-#[derive(Clone)]
+pub mod bit;
+pub mod float;
+pub mod sparse;
+use algebra::{Semiring, range_to_pair};
+use std::ops::RangeBounds;
+#[derive(Clone, PartialEq, Eq)]
 pub struct Matrix<T: Copy> {
     h: usize,
     w: usize,
@@ -17,11 +22,147 @@ impl<T: Copy> Matrix<T> {
         }
     }
     pub fn iter(&self) -> impl Iterator<Item = &[T]> {
-        self.val.chunks_exact(self.w)
+        let empty = if self.w == 0 { self.h } else { 0 };
+        self.val
+            .chunks_exact(self.w.max(1))
+            .chain(std::iter::repeat_n(&[][..], empty))
     }
 
     pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut [T]> {
-        self.val.chunks_exact_mut(self.w)
+        let empty = if self.w == 0 { self.h } else { 0 };
+        self.val
+            .chunks_exact_mut(self.w.max(1))
+            .chain(std::iter::repeat_with(<&mut [T]>::default).take(empty))
+    }
+    /// Return the matrix whose `(i, j)` element is `f(i, j)`.
+    /// This function has a time complexity of O(h w).
+    pub fn from_fn(h: usize, w: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+        let mut val = Vec::with_capacity(h * w);
+        for i in 0..h {
+            val.extend((0..w).map(|j| f(i, j)));
+        }
+        Self {
+            h,
+            w,
+            val: val.into_boxed_slice(),
+        }
+    }
+    /// Return the number of rows.
+    /// This function has a time complexity of O(1).
+    pub fn h(&self) -> usize {
+        self.h
+    }
+    /// Return the number of columns.
+    /// This function has a time complexity of O(1).
+    pub fn w(&self) -> usize {
+        self.w
+    }
+    /// Return `i` th row.
+    /// This function has a time complexity of O(1).
+    pub fn row(&self, i: usize) -> &[T] {
+        &self[i]
+    }
+    /// Return `j` th column.
+    /// This function has a time complexity of O(h).
+    pub fn col(&self, j: usize) -> impl Iterator<Item = &T> {
+        assert!(j < self.w);
+        self.val.iter().skip(j).step_by(self.w)
+    }
+    /// Return the transposed matrix.
+    /// This function has a time complexity of O(h w).
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.w, self.h, |i, j| self[j][i])
+    }
+    /// Return the view of the block which rows are in `rows` and columns are in `cols`.
+    /// This function has a time complexity of O(1).
+    pub fn view(
+        &self,
+        rows: impl RangeBounds<usize>,
+        cols: impl RangeBounds<usize>,
+    ) -> MatrixView<'_, T> {
+        let (top, bottom) = range_to_pair(rows, self.h);
+        let (left, right) = range_to_pair(cols, self.w);
+        assert!(top <= bottom && bottom <= self.h && left <= right && right <= self.w);
+        MatrixView {
+            mat: self,
+            top,
+            bottom,
+            left,
+            right,
+        }
+    }
+    /// Return the block matrix `[self other]`.
+    /// This function has a time complexity of O(h (w1 + w2)).
+    pub fn hstack(&self, other: &Self) -> Self {
+        assert_eq!(self.h, other.h);
+        Self::from_fn(self.h, self.w + other.w, |i, j| {
+            if j < self.w {
+                self[i][j]
+            } else {
+                other[i][j - self.w]
+            }
+        })
+    }
+    /// Return the block matrix `[self; other]`.
+    /// This function has a time complexity of O((h1 + h2) w).
+    pub fn vstack(&self, other: &Self) -> Self {
+        assert_eq!(self.w, other.w);
+        let mut val = Vec::with_capacity((self.h + other.h) * self.w);
+        val.extend_from_slice(&self.val);
+        val.extend_from_slice(&other.val);
+        Self {
+            h: self.h + other.h,
+            w: self.w,
+            val: val.into_boxed_slice(),
+        }
+    }
+}
+/// Borrowed block of `Matrix`.
+///
+/// # Example
+///
+/// ```
+/// use matrix::Matrix;
+/// let a = Matrix::from_fn(3, 3, |i, j| 3 * i + j);
+/// let v = a.view(1.., ..2);
+/// assert_eq!(v[1], [6, 7]);
+/// assert_eq!(v.to_matrix(), Matrix::new(2, 2, &[vec![3, 4], vec![6, 7]]));
+/// ```
+///
+pub struct MatrixView<'a, T: Copy> {
+    mat: &'a Matrix<T>,
+    top: usize,
+    bottom: usize,
+    left: usize,
+    right: usize,
+}
+impl<T: Copy> MatrixView<'_, T> {
+    /// Return the number of rows.
+    /// This function has a time complexity of O(1).
+    pub fn h(&self) -> usize {
+        self.bottom - self.top
+    }
+    /// Return the number of columns.
+    /// This function has a time complexity of O(1).
+    pub fn w(&self) -> usize {
+        self.right - self.left
+    }
+    /// Return the iterator of rows.
+    /// This function has a time complexity of O(1).
+    pub fn iter(&self) -> impl Iterator<Item = &[T]> {
+        (self.top..self.bottom).map(|i| &self.mat[i][self.left..self.right])
+    }
+    /// Copy the block to a new `Matrix`.
+    /// This function has a time complexity of O(h w).
+    pub fn to_matrix(&self) -> Matrix<T> {
+        Matrix::from_fn(self.h(), self.w(), |i, j| self[i][j])
+    }
+}
+impl<T: Copy> std::ops::Index<usize> for MatrixView<'_, T> {
+    type Output = [T];
+    fn index(&self, index: usize) -> &Self::Output {
+        assert!(index < self.h());
+        &self.mat[self.top + index][self.left..self.right]
     }
 }
 impl<T: Copy + std::ops::Add<Output = T>> Matrix<T> {
@@ -69,10 +210,31 @@ impl<T: Copy + std::ops::Sub<Output = T>> Matrix<T> {
     }
 }
 impl<T: Copy + std::ops::Add<Output = T> + std::ops::Mul<Output = T> + From<u8>> Matrix<T> {
+    /// Return the zero matrix of size `h` x `w`.
+    /// This function has a time complexity of O(h w).
+    pub fn zeros(h: usize, w: usize) -> Self {
+        Self {
+            h,
+            w,
+            val: vec![0u8.into(); h * w].into_boxed_slice(),
+        }
+    }
+    /// Return the product of `self` and column vector `v`.
+    /// This function has a time complexity of O(h w).
+    pub fn mul_vec(&self, v: &[T]) -> Vec<T> {
+        assert_eq!(self.w, v.len());
+        self.iter()
+            .map(|r| {
+                r.iter()
+                    .zip(v.iter())
+                    .fold(0u8.into(), |acc, (a, b)| acc + *a * *b)
+            })
+            .collect()
+    }
     pub fn e(n: usize) -> Self {
         let mut val = vec![0u8.into(); n * n];
         for i in 0..n {
-            val[i * n] = 1u8.into();
+            val[i * n + i] = 1u8.into();
         }
         Self {
             h: n,
@@ -96,6 +258,11 @@ impl<T: Copy + std::ops::Add<Output = T> + std::ops::Mul<Output = T> + From<u8>>
         }
         res
     }
+    /// Update `self` to `self * other`.
+    /// This function has a time complexity of O(h w w').
+    pub fn mul_assign(&mut self, other: &Self) {
+        *self = Matrix::mul(self, other);
+    }
     pub fn pow(&self, mut k: u64) -> Self {
         assert_eq!(self.h, self.w);
         let n = self.h;
@@ -103,9 +270,9 @@ impl<T: Copy + std::ops::Add<Output = T> + std::ops::Mul<Output = T> + From<u8>>
         let mut x = self.clone();
         while k > 0 {
             if k & 1 == 1 {
-                res = res.mul(&x);
+                res = &res * &x;
             }
-            x = x.mul(&x);
+            x = &x * &x;
             k >>= 1;
         }
         res
@@ -117,14 +284,62 @@ impl<T: Copy + std::ops::Add<Output = T> + std::ops::Mul<Output = T> + From<u8>>
         let mut res = Self::e(n);
         while k > 0 {
             if k & 1 == 1 {
-                res = res.mul(&self);
+                res = &res * &*self;
             }
-            *self = self.mul(&self);
+            *self = &*self * &*self;
             k >>= 1;
         }
         swap(&mut self.val, &mut res.val);
     }
 }
+impl<T: Copy> Matrix<T> {
+    /// Return the identity matrix of size `n` over semiring `R`.
+    /// This function has a time complexity of O(n^2).
+    pub fn semiring_e<R: Semiring<S = T>>(n: usize) -> Self {
+        let mut val = vec![R::ZERO; n * n];
+        for i in 0..n {
+            val[i * n + i] = R::ONE;
+        }
+        Self {
+            h: n,
+            w: n,
+            val: val.into_boxed_slice(),
+        }
+    }
+    /// Return the product of `self` and `other` over semiring `R`.
+    /// This function has a time complexity of O(h w l).
+    pub fn semiring_mul<R: Semiring<S = T>>(&self, other: &Self) -> Self {
+        assert_eq!(self.w, other.h);
+        let mut res = Self {
+            h: self.h,
+            w: other.w,
+            val: vec![R::ZERO; self.h * other.w].into_boxed_slice(),
+        };
+        for (res_r, self_r) in res.iter_mut().zip(self.iter()) {
+            for (self_val, other_r) in self_r.iter().zip(other.iter()) {
+                for (res_val, other_val) in res_r.iter_mut().zip(other_r.iter()) {
+                    *res_val = R::add(res_val, &R::mul(self_val, other_val));
+                }
+            }
+        }
+        res
+    }
+    /// Return `self^k` over semiring `R`.
+    /// This function has a time complexity of O(n^3 log k).
+    pub fn semiring_pow<R: Semiring<S = T>>(&self, mut k: u64) -> Self {
+        assert_eq!(self.h, self.w);
+        let mut res = Self::semiring_e::<R>(self.h);
+        let mut x = self.clone();
+        while k > 0 {
+            if k & 1 == 1 {
+                res = res.semiring_mul::<R>(&x);
+            }
+            x = x.semiring_mul::<R>(&x);
+            k >>= 1;
+        }
+        res
+    }
+}
 impl<
         T: Copy
             + Eq
@@ -169,12 +384,274 @@ impl<
         }
         res
     }
+    /// Transform `self` into reduced row echelon form, and return its rank.
+    /// This function has a time complexity of O(h w min(h, w)).
     pub fn gaussian_elimination(&mut self) -> usize {
+        self.pivots().len()
+    }
+    /// Return the rank of `self`.
+    /// This function has a time complexity of O(h w min(h, w)).
+    pub fn rank(&self) -> usize {
+        self.clone().gaussian_elimination()
+    }
+    /// Return a basis of `{ x | self * x = 0 }`.
+    /// This function has a time complexity of O(h w min(h, w) + w^2).
+    pub fn kernel(&self) -> Vec<Vec<T>> {
+        let mut a = self.clone();
+        let pivots = a.pivots();
+        a.free_basis(&pivots, self.w)
+    }
+    /// Return a solution `x` of `self * x = b` and a basis of the kernel of `self`.
+    /// Every solution is `x` plus a linear combination of the basis.
+    /// If there is no solution, return `None`.
+    /// This function has a time complexity of O(h w min(h, w) + w^2).
+    pub fn solve(&self, b: &[T]) -> Option<(Vec<T>, Vec<Vec<T>>)> {
+        assert_eq!(self.h, b.len());
+        let (h, w) = (self.h, self.w);
+        let mut val = Vec::with_capacity(h * (w + 1));
+        for (r, b) in self.iter().zip(b.iter()) {
+            val.extend_from_slice(r);
+            val.push(*b);
+        }
+        let mut a = Self {
+            h,
+            w: w + 1,
+            val: val.into_boxed_slice(),
+        };
+        let pivots = a.pivots();
+        if pivots.last() == Some(&w) {
+            return None;
+        }
+        let mut x = vec![0u8.into(); w];
+        for (r, &p) in a.iter().zip(pivots.iter()) {
+            x[p] = r[w];
+        }
+        let kernel = a.free_basis(&pivots, w);
+        Some((x, kernel))
+    }
+    /// Return the inverse matrix of `self`.
+    /// If `self` is singular, return `None`.
+    /// This function has a time complexity of O(n^3).
+    pub fn inverse(&self) -> Option<Self> {
+        assert_eq!(self.h, self.w);
+        let n = self.h;
+        let mut val = Vec::with_capacity(2 * n * n);
+        for (i, r) in self.iter().enumerate() {
+            val.extend_from_slice(r);
+            val.extend((0..n).map(|j| <u8 as Into<T>>::into((i == j) as u8)));
+        }
+        let mut a = Self {
+            h: n,
+            w: 2 * n,
+            val: val.into_boxed_slice(),
+        };
+        let pivots = a.pivots();
+        if pivots.len() < n || pivots.last().is_some_and(|&p| p >= n) {
+            return None;
+        }
+        let mut val = Vec::with_capacity(n * n);
+        for r in a.iter() {
+            val.extend_from_slice(&r[n..]);
+        }
+        Some(Self {
+            h: n,
+            w: n,
+            val: val.into_boxed_slice(),
+        })
+    }
+    /// Return the characteristic polynomial `det(x I - self)` from the lowest degree.
+    /// This function has a time complexity of O(n^3).
+    pub fn characteristic_polynomial(&self) -> Vec<T> {
+        assert_eq!(self.h, self.w);
+        let n = self.h;
+        let zero: T = 0u8.into();
+        let mut a = self.clone();
+        for j in 0..n.saturating_sub(2) {
+            if let Some(p) = (j + 1..n).find(|i| a[*i][j] != zero) {
+                if p != j + 1 {
+                    for k in 0..n {
+                        a.val.swap(p * n + k, (j + 1) * n + k);
+                    }
+                    for r in a.iter_mut() {
+                        r.swap(p, j + 1);
+                    }
+                }
+                let inv = <u8 as Into<T>>::into(1u8) / a[j + 1][j];
+                for i in j + 2..n {
+                    let t = a[i][j] * inv;
+                    if t == zero {
+                        continue;
+                    }
+                    for k in 0..n {
+                        let v = a[j + 1][k];
+                        a[i][k] = a[i][k] - t * v;
+                    }
+                    for r in a.iter_mut() {
+                        r[j + 1] = r[j + 1] + t * r[i];
+                    }
+                }
+            }
+        }
+        let mut ps: Vec<Vec<T>> = vec![vec![1u8.into()]];
+        for i in 0..n {
+            let mut p = vec![zero; i + 2];
+            for (k, c) in ps[i].iter().enumerate() {
+                p[k + 1] = p[k + 1] + *c;
+                p[k] = p[k] - a[i][i] * *c;
+            }
+            let mut prod: T = 1u8.into();
+            for j in (0..i).rev() {
+                prod = prod * a[j + 1][j];
+                let c = a[j][i] * prod;
+                for (p, q) in p.iter_mut().zip(ps[j].iter()) {
+                    *p = *p - c * *q;
+                }
+            }
+            ps.push(p);
+        }
+        ps.pop().unwrap()
+    }
+    /// Return `self^k` by reducing `x^k` modulo the characteristic polynomial
+    /// and evaluating the remainder by the Paterson-Stockmeyer method.
+    /// This function has a time complexity of O(n^3 sqrt n + n^2 log k).
+    pub fn pow_via_charpoly(&self, mut k: u64) -> Self {
+        assert_eq!(self.h, self.w);
+        let n = self.h;
+        if n == 0 {
+            return self.clone();
+        }
+        let p = self.characteristic_polynomial();
+        let mut res: Vec<T> = vec![1u8.into()];
+        let mut x = Self::poly_mulmod(&[0u8.into(), 1u8.into()], &[1u8.into()], &p);
+        while k > 0 {
+            if k & 1 == 1 {
+                res = Self::poly_mulmod(&res, &x, &p);
+            }
+            x = Self::poly_mulmod(&x, &x, &p);
+            k >>= 1;
+        }
+        let s = res.len().isqrt().max(1);
+        let mut pows = vec![Self::e(n)];
+        for i in 0..s {
+            let next = &pows[i] * self;
+            pows.push(next);
+        }
+        let block = |c: &[T]| {
+            let mut b = Self::zeros(n, n);
+            for (c, a) in c.iter().zip(pows.iter()) {
+                for (v, a) in b.val.iter_mut().zip(a.val.iter()) {
+                    *v = *v + *c * *a;
+                }
+            }
+            b
+        };
+        let mut blocks = res.chunks(s).rev();
+        let mut acc = block(blocks.next().unwrap());
+        for c in blocks {
+            acc = &acc * &pows[s];
+            acc.add_assign(&block(c));
+        }
+        acc
+    }
+    /// Return `det(self + x other)` from the lowest degree.
+    /// This function has a time complexity of O(n^3).
+    pub fn linear_determinant(&self, other: &Self) -> Vec<T> {
+        assert_eq!(self.h, self.w);
+        assert_eq!(other.h, other.w);
+        assert_eq!(self.h, other.h);
+        let n = self.h;
+        let zero: T = 0u8.into();
+        let mut a = self.clone();
+        let mut b = other.clone();
+        let mut prod: T = 1u8.into();
+        let mut shift = 0;
+        let mut p = 0;
+        while p < n {
+            if let Some(r) = (p..n).find(|r| b[*r][p] != zero) {
+                if r != p {
+                    for k in 0..n {
+                        a.val.swap(r * n + k, p * n + k);
+                        b.val.swap(r * n + k, p * n + k);
+                    }
+                    prod = -prod;
+                }
+                prod = prod * b[p][p];
+                let inv = <u8 as Into<T>>::into(1u8) / b[p][p];
+                for k in 0..n {
+                    a[p][k] = a[p][k] * inv;
+                    b[p][k] = b[p][k] * inv;
+                }
+                for r in (0..n).filter(|r| *r != p) {
+                    let c = b[r][p];
+                    if c == zero {
+                        continue;
+                    }
+                    for k in 0..n {
+                        let (x, y) = (a[p][k], b[p][k]);
+                        a[r][k] = a[r][k] - c * x;
+                        b[r][k] = b[r][k] - c * y;
+                    }
+                }
+                p += 1;
+            } else {
+                shift += 1;
+                if shift > n {
+                    return vec![zero; n + 1];
+                }
+                for r in 0..p {
+                    let c = b[r][p];
+                    for i in 0..n {
+                        let (x, y) = (a[i][r], b[i][r]);
+                        a[i][p] = a[i][p] - c * x;
+                        b[i][p] = b[i][p] - c * y;
+                    }
+                }
+                for i in 0..n {
+                    b[i][p] = a[i][p];
+                    a[i][p] = zero;
+                }
+            }
+        }
+        for v in a.val.iter_mut() {
+            *v = -*v;
+        }
+        let mut res = a
+            .characteristic_polynomial()
+            .into_iter()
+            .skip(shift)
+            .map(|c| c * prod)
+            .collect::<Vec<_>>();
+        res.resize(n + 1, zero);
+        res
+    }
+    /// Return `a * b mod p` where `p` is monic.
+    /// This function has a time complexity of O(deg(p)^2).
+    fn poly_mulmod(a: &[T], b: &[T], p: &[T]) -> Vec<T> {
+        let n = p.len() - 1;
+        let mut c = vec![0u8.into(); a.len() + b.len() - 1];
+        for (i, a) in a.iter().enumerate() {
+            for (j, b) in b.iter().enumerate() {
+                c[i + j] = c[i + j] + *a * *b;
+            }
+        }
+        for i in (n..c.len()).rev() {
+            let t = c[i];
+            for (c, p) in c[i - n..i].iter_mut().zip(p.iter()) {
+                *c = *c - t * *p;
+            }
+        }
+        c.truncate(n);
+        c
+    }
+    /// Transform `self` into reduced row echelon form, and return its pivot columns.
+    /// This function has a time complexity of O(h w min(h, w)).
+    fn pivots(&mut self) -> Vec<usize> {
         let h = self.h;
         let w = self.w;
-        let mut x = 0;
+        let mut pivots = Vec::with_capacity(h.min(w));
         let mut tmp = Vec::with_capacity(w);
         for y in 0..w {
+            let x = pivots.len();
             if let Some(k) = (x..h).find(|k| self[*k][y] != 0u8.into()) {
                 for j in 0..w {
                     self.val.swap(x * w + j, k * w + j);
@@ -194,10 +671,118 @@ impl<
                         *v = *v - p * *tmp;
                     }
                 }
-                x += 1;
+                pivots.push(y);
+            }
+        }
+        pivots
+    }
+    /// Return a kernel basis of reduced row echelon form `self` restricted to the first `w` columns.
+    /// This function has a time complexity of O(w^2).
+    fn free_basis(&self, pivots: &[usize], w: usize) -> Vec<Vec<T>> {
+        let mut is_pivot = vec![false; w];
+        for &p in pivots.iter().filter(|p| **p < w) {
+            is_pivot[p] = true;
+        }
+        (0..w)
+            .filter(|f| !is_pivot[*f])
+            .map(|f| {
+                let mut v = vec![0u8.into(); w];
+                v[f] = 1u8.into();
+                for (r, &p) in self.iter().zip(pivots.iter()) {
+                    if p < w {
+                        v[p] = -r[f];
+                    }
+                }
+                v
+            })
+            .collect()
+    }
+}
+impl<
+    T: Copy
+        + Eq
+        + std::ops::Add<Output = T>
+        + std::ops::Sub<Output = T>
+        + std::ops::Mul<Output = T>
+        + std::ops::Div<Output = T>
+        + std::ops::Neg<Output = T>
+        + From<u8>,
+> Matrix<T>
+{
+    /// Return the determinant by Bareiss algorithm.
+    /// `T` must be an integral domain whose `Div` is exact, such as `i64` or `i128`.
+    /// Every intermediate value is a minor of `self`,
+    /// but the product of two minors must fit in `T` to avoid overflow.
+    /// This function has a time complexity of O(n^3).
+    pub fn bareiss_determinant(&self) -> T {
+        assert_eq!(self.h, self.w);
+        let n = self.h;
+        let zero: T = 0u8.into();
+        let mut a = self.clone();
+        let mut sign = false;
+        let mut prev: T = 1u8.into();
+        for k in 0..n {
+            if let Some(p) = (k..n).find(|i| a[*i][k] != zero) {
+                if p != k {
+                    for j in 0..n {
+                        a.val.swap(p * n + j, k * n + j);
+                    }
+                    sign = !sign;
+                }
+            } else {
+                return zero;
             }
+            for i in k + 1..n {
+                for j in k + 1..n {
+                    a[i][j] = (a[i][j] * a[k][k] - a[i][k] * a[k][j]) / prev;
+                }
+            }
+            prev = a[k][k];
+        }
+        if n == 0 {
+            1u8.into()
+        } else if sign {
+            -a[n - 1][n - 1]
+        } else {
+            a[n - 1][n - 1]
         }
-        x
+    }
+}
+impl<T: Copy + Into<i128>> Matrix<T> {
+    /// Return the determinant modulo `m` which is not necessarily prime.
+    /// Any `m` up to `u64::MAX` is supported since reductions are done in u128.
+    /// This function has a time complexity of O(n^3 + n^2 log m).
+    pub fn determinant_mod(&self, m: u64) -> u64 {
+        assert_eq!(self.h, self.w);
+        assert!(m > 0);
+        let n = self.h;
+        let mul = |a: u64, b: u64| (a as u128 * b as u128 % m as u128) as u64;
+        let mut a = self
+            .val
+            .iter()
+            .map(|v| (*v).into().rem_euclid(m as i128) as u64)
+            .collect::<Vec<_>>();
+        let mut res = 1 % m;
+        for i in 0..n {
+            for j in i + 1..n {
+                while a[j * n + i] != 0 {
+                    let q = a[i * n + i] / a[j * n + i];
+                    if q != 0 {
+                        for k in i..n {
+                            let t = mul(q, a[j * n + k]);
+                            a[i * n + k] =
+                                ((a[i * n + k] as u128 + m as u128 - t as u128) % m as u128) as u64;
+                        }
+                    }
+                    for k in i..n {
+                        a.swap(i * n + k, j * n + k);
+                    }
+                    res = (m - res) % m;
+                }
+            }
+            res = mul(res, a[i * n + i]);
+        }
+        res
     }
 }
 impl<T: Copy> std::ops::Index<usize> for Matrix<T> {
@@ -211,3 +796,96 @@ impl<T: Copy> std::ops::IndexMut<usize> for Matrix<T> {
         &mut self.val[index * self.w..(index + 1) * self.w]
     }
 }
+use std::fmt::{Debug, Display, Formatter, Result};
+impl<T: Copy + Debug> Debug for Matrix<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+impl<T: Copy + Display> Display for Matrix<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        for (i, r) in self.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for (j, v) in r.iter().enumerate() {
+                if j > 0 {
+                    write!(f, " ")?;
+                }
+                write!(f, "{}", v)?;
+            }
+        }
+        Ok(())
+    }
+}
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+macro_rules! matrix_ops {
+    ($(
+            $trait:ident,
+            $trait_assign:ident,
+            $fn:ident,
+            $fn_assign:ident,
+            [$($bound:tt)*],
+    )*) => {$(
+        impl<T: Copy + $($bound)*> $trait_assign<&Matrix<T>> for Matrix<T> {
+            fn $fn_assign(&mut self, rhs: &Matrix<T>) {
+                Matrix::$fn_assign(self, rhs);
+            }
+        }
+        impl<T: Copy + $($bound)*> $trait_assign<Matrix<T>> for Matrix<T> {
+            fn $fn_assign(&mut self, rhs: Matrix<T>) {
+                Matrix::$fn_assign(self, &rhs);
+            }
+        }
+        impl<T: Copy + $($bound)*> $trait<Matrix<T>> for Matrix<T> {
+            type Output = Matrix<T>;
+            fn $fn(self, rhs: Matrix<T>) -> Self::Output {
+                Matrix::$fn(&self, &rhs)
+            }
+        }
+        impl<T: Copy + $($bound)*> $trait<&Matrix<T>> for Matrix<T> {
+            type Output = Matrix<T>;
+            fn $fn(self, rhs: &Matrix<T>) -> Self::Output {
+                Matrix::$fn(&self, rhs)
+            }
+        }
+        impl<T: Copy + $($bound)*> $trait<Matrix<T>> for &Matrix<T> {
+            type Output = Matrix<T>;
+            fn $fn(self, rhs: Matrix<T>) -> Self::Output {
+                Matrix::$fn(self, &rhs)
+            }
+        }
+        impl<T: Copy + $($bound)*> $trait<&Matrix<T>> for &Matrix<T> {
+            type Output = Matrix<T>;
+            fn $fn(self, rhs: &Matrix<T>) -> Self::Output {
+                Matrix::$fn(self, rhs)
+            }
+        }
+    )*};
+}
+matrix_ops! {
+    Add, AddAssign, add, add_assign, [Add<Output = T>],
+    Sub, SubAssign, sub, sub_assign, [Sub<Output = T>],
+    Mul, MulAssign, mul, mul_assign, [Add<Output = T> + Mul<Output = T> + From<u8>],
+}
+impl<T: Copy + Add<Output = T> + Mul<Output = T> + From<u8>> Mul<&[T]> for &Matrix<T> {
+    type Output = Vec<T>;
+    fn mul(self, rhs: &[T]) -> Self::Output {
+        self.mul_vec(rhs)
+    }
+}
+impl<T: Copy + Neg<Output = T>> Neg for Matrix<T> {
+    type Output = Matrix<T>;
+    fn neg(mut self) -> Self::Output {
+        for v in self.val.iter_mut() {
+            *v = -*v;
+        }
+        self
+    }
+}
+impl<T: Copy + Neg<Output = T>> Neg for &Matrix<T> {
+    type Output = Matrix<T>;
+    fn neg(self) -> Self::Output {
+        -self.clone()
+    }
+}
@@ -0,0 +1,247 @@
+use gf::GF;
+use gf::berlekamp_massey::berlekamp_massey;
+/// Sparse matrix in compressed sparse row format.
+///
+/// # Example
+///
+/// ```
+/// use matrix::sparse::SparseMatrix;
+/// let a = SparseMatrix::new(2, 3, &[(0, 0, 1), (0, 2, 2), (1, 1, 3)]);
+/// assert_eq!(a.mul_vec(&[1, 1, 1]), vec![3, 3]);
+/// ```
+///
+#[derive(Clone)]
+pub struct SparseMatrix<T: Copy> {
+    h: usize,
+    w: usize,
+    start: Vec<usize>,
+    col: Vec<usize>,
+    val: Vec<T>,
+}
+impl<T: Copy> SparseMatrix<T> {
+    /// This is initializer of `SparseMatrix` from `(row, column, value)` entries.
+    /// Duplicated entries are regarded as their sum.
+    /// This function has a time complexity of O(h + nnz).
+    pub fn new(h: usize, w: usize, entries: &[(usize, usize, T)]) -> Self {
+        let mut start = vec![0; h + 1];
+        for &(i, j, _) in entries {
+            assert!(i < h && j < w);
+            start[i + 1] += 1;
+        }
+        for i in 0..h {
+            start[i + 1] += start[i];
+        }
+        let mut pos = start.clone();
+        let mut col = vec![0; entries.len()];
+        let mut val = entries.iter().map(|e| e.2).collect::<Vec<_>>();
+        for &(i, j, v) in entries {
+            col[pos[i]] = j;
+            val[pos[i]] = v;
+            pos[i] += 1;
+        }
+        Self {
+            h,
+            w,
+            start,
+            col,
+            val,
+        }
+    }
+    /// Return the number of rows.
+    /// This function has a time complexity of O(1).
+    pub fn h(&self) -> usize {
+        self.h
+    }
+    /// Return the number of columns.
+    /// This function has a time complexity of O(1).
+    pub fn w(&self) -> usize {
+        self.w
+    }
+    /// Return the number of stored entries.
+    /// This function has a time complexity of O(1).
+    pub fn nnz(&self) -> usize {
+        self.val.len()
+    }
+    /// Return the entries `(column, value)` of `i` th row.
+    /// This function has a time complexity of O(1).
+    pub fn row(&self, i: usize) -> impl Iterator<Item = (usize, &T)> {
+        let range = self.start[i]..self.start[i + 1];
+        self.col[range.clone()]
+            .iter()
+            .copied()
+            .zip(self.val[range].iter())
+    }
+    /// Return the transposed matrix.
+    /// This function has a time complexity of O(h + w + nnz).
+    pub fn transpose(&self) -> Self {
+        let entries = (0..self.h)
+            .flat_map(|i| self.row(i).map(move |(j, v)| (j, i, *v)))
+            .collect::<Vec<_>>();
+        Self::new(self.w, self.h, &entries)
+    }
+}
+impl<T: Copy + std::ops::Add<Output = T> + std::ops::Mul<Output = T> + From<u8>> SparseMatrix<T> {
+    /// Return the product of `self` and column vector `v`.
+    /// This function has a time complexity of O(h + nnz).
+    pub fn mul_vec(&self, v: &[T]) -> Vec<T> {
+        assert_eq!(self.w, v.len());
+        (0..self.h)
+            .map(|i| self.row(i).fold(0u8.into(), |acc, (j, a)| acc + *a * v[j]))
+            .collect()
+    }
+}
+impl<const MOD: u32> SparseMatrix<GF<MOD>> {
+    /// Return the determinant by Wiedemann algorithm.
+    /// This is a Monte Carlo algorithm which may return 0 for a nonsingular matrix
+    /// with probability about (n^2 / MOD)^3, since it retries with fresh seeds.
+    /// This function has a time complexity of O(n (n + nnz)).
+    pub fn determinant(&self) -> GF<MOD> {
+        assert_eq!(self.h, self.w);
+        let n = self.h;
+        if n == 0 {
+            return GF::new(1);
+        }
+        for _ in 0..TRIALS {
+            let mut rng = Xorshift::new();
+            let d = (0..n).map(|_| rng.nonzero::<MOD>()).collect::<Vec<_>>();
+            let c = self.min_poly(&mut rng, |v| {
+                let v = v
+                    .iter()
+                    .zip(d.iter())
+                    .map(|(v, d)| v * d)
+                    .collect::<Vec<_>>();
+                self.mul_vec(&v)
+            });
+            if c.len() == n {
+                let det = d.iter().fold(-c[n - 1], |acc, d| acc / d);
+                return if n & 1 == 1 { -det } else { det };
+            }
+        }
+        GF::new(0)
+    }
+    /// Return the rank by Wiedemann algorithm with preconditioner `D1 A^T D2 A D1`.
+    /// A trial never overestimates the rank and fails with probability at most about
+    /// (h + w)^2 / MOD, so this returns the maximum of 3 trials with fresh seeds.
+    /// This function has a time complexity of O(w (h + w + nnz)).
+    pub fn rank(&self) -> usize {
+        let t = self.transpose();
+        (0..TRIALS)
+            .map(|_| self.rank_once(&t))
+            .max()
+            .unwrap_or(0)
+    }
+    /// Return the rank by one trial of Wiedemann algorithm, where `t` is the transposed matrix.
+    /// This function has a time complexity of O(w (h + w + nnz)).
+    fn rank_once(&self, t: &Self) -> usize {
+        let mut rng = Xorshift::new();
+        let d1 = (0..self.w)
+            .map(|_| rng.nonzero::<MOD>())
+            .collect::<Vec<_>>();
+        let d2 = (0..self.h)
+            .map(|_| rng.nonzero::<MOD>())
+            .collect::<Vec<_>>();
+        let c = self.min_poly(&mut rng, |v| {
+            let v = v
+                .iter()
+                .zip(d1.iter())
+                .map(|(v, d)| v * d)
+                .collect::<Vec<_>>();
+            let v = self.mul_vec(&v);
+            let v = v
+                .iter()
+                .zip(d2.iter())
+                .map(|(v, d)| v * d)
+                .collect::<Vec<_>>();
+            let v = t.mul_vec(&v);
+            v.iter().zip(d1.iter()).map(|(v, d)| v * d).collect()
+        });
+        match c.last() {
+            Some(last) if *last == GF::new(0) => c.len() - 1,
+            _ => c.len(),
+        }
+    }
+    /// Return `x` such that `self * x = b` by Wiedemann algorithm.
+    /// The result is verified and the method is retried with fresh seeds up to 3 times.
+    /// If `self` is singular or every trial fails, return `None`.
+    /// This function has a time complexity of O(n (n + nnz)).
+    pub fn solve(&self, b: &[GF<MOD>]) -> Option<Vec<GF<MOD>>> {
+        assert_eq!(self.h, self.w);
+        assert_eq!(self.h, b.len());
+        let zero = GF::<MOD>::new(0);
+        if b.iter().all(|b| *b == zero) {
+            return Some(vec![zero; self.h]);
+        }
+        (0..TRIALS).find_map(|_| self.solve_once(b))
+    }
+    /// Return `x` such that `self * x = b` by one trial of Wiedemann algorithm.
+    /// This function has a time complexity of O(n (n + nnz)).
+    fn solve_once(&self, b: &[GF<MOD>]) -> Option<Vec<GF<MOD>>> {
+        let n = self.h;
+        let zero = GF::<MOD>::new(0);
+        let mut rng = Xorshift::new();
+        let u = (0..n).map(|_| rng.gf::<MOD>()).collect::<Vec<_>>();
+        let mut s = Vec::with_capacity(2 * n);
+        let mut v = b.to_vec();
+        for _ in 0..2 * n {
+            s.push(u.iter().zip(v.iter()).map(|(u, v)| u * v).sum::<GF<MOD>>());
+            v = self.mul_vec(&v);
+        }
+        let c = berlekamp_massey(&s);
+        let l = c.len();
+        if l == 0 || c[l - 1] == zero {
+            return None;
+        }
+        let mut x = b.to_vec();
+        for c in c[..l - 1].iter() {
+            x = self.mul_vec(&x);
+            for (x, b) in x.iter_mut().zip(b.iter()) {
+                *x -= c * b;
+            }
+        }
+        let inv = c[l - 1].inv();
+        for x in x.iter_mut() {
+            *x *= inv;
+        }
+        if self.mul_vec(&x) == b { Some(x) } else { None }
+    }
+    /// Return the recurrence of `u^T M^i v` for random `u` and `v` where `f` is `v -> M v`.
+    /// This function has a time complexity of O(n T(f) + n^2).
+    fn min_poly(
+        &self,
+        rng: &mut Xorshift,
+        mut f: impl FnMut(&[GF<MOD>]) -> Vec<GF<MOD>>,
+    ) -> Vec<GF<MOD>> {
+        let n = self.w;
+        let u = (0..n).map(|_| rng.gf::<MOD>()).collect::<Vec<_>>();
+        let mut v = (0..n).map(|_| rng.gf::<MOD>()).collect::<Vec<_>>();
+        let mut s = Vec::with_capacity(2 * n);
+        for _ in 0..2 * n {
+            s.push(u.iter().zip(v.iter()).map(|(u, v)| u * v).sum::<GF<MOD>>());
+            v = f(&v);
+        }
+        berlekamp_massey(&s)
+    }
+}
+/// The number of trials of randomized algorithms before giving up.
+const TRIALS: usize = 3;
+/// Pseudo random generator for randomized algorithms.
+struct Xorshift(u64);
+impl Xorshift {
+    /// Return a generator with a fresh random seed for each call.
+    fn new() -> Self {
+        use std::hash::{BuildHasher, RandomState};
+        Self(RandomState::new().hash_one(0x2545_f491_4f6c_dd1d_u64) | 1)
+    }
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+    fn gf<const MOD: u32>(&mut self) -> GF<MOD> {
+        GF::new((self.next() % MOD as u64) as u32)
+    }
+    fn nonzero<const MOD: u32>(&mut self) -> GF<MOD> {
+        GF::new((self.next() % (MOD as u64 - 1)) as u32 + 1)
+    }
+}
@@ -0,0 +1,74 @@
+use std::ops::{Add, Mul, Sub};
+/// Return `g(k)` for convex `g` by Alien's trick (WQS binary search),
+/// where `g(k)` is the minimum cost of solutions with exactly `k` items.
+///
+/// `solver(lambda)` must return the minimum of `cost + lambda * count` over all solutions,
+/// and the maximum `count` among the minimizers.
+/// `lambda` is searched over `t * step` for integers `t` in `[lo, hi]`,
+/// so every slope `g(i + 1) - g(i)` must be a multiple of `step`
+/// (`step = 1` for integers, `Rational::new(1, d)` for rationals).
+/// `solver(lo * step)` and `solver(hi * step)` must have many and few items respectively.
+/// If `solver(lo * step)` has less than `k` items, return `None`.
+///
+/// For example, `solver` can be `monge::larsch_with_count` in which every transition
+/// costs extra `lambda`, or a DP over `slopetrick::SlopeTrick`.
+///
+/// # Example
+///
+/// ```
+/// use optim::alien::alien_trick;
+/// let a = [3i64, -1, 4, -5, 2];
+/// let solver = |lambda: i64| {
+///     let chosen = a.iter().filter(|x| **x + lambda <= 0);
+///     (chosen.clone().map(|x| x + lambda).sum::<i64>(), chosen.count())
+/// };
+/// assert_eq!(alien_trick(3, -10, 10, 1, solver), Some(-4));
+/// assert_eq!(alien_trick(6, -10, 10, 1, solver), None);
+/// ```
+///
+/// Division of an array into `k` segments minimizing the sum of squared segment sums
+/// with `monge::larsch_with_count`.
+///
+/// ```
+/// use optim::alien::alien_trick;
+/// use optim::monge::{divide_and_conquer_dp, larsch_with_count};
+/// let a = [3i64, 1, 4, 1, 5, 9, 2, 6];
+/// let n = a.len();
+/// let cost = |j: usize, i: usize| a[j..i].iter().sum::<i64>().pow(2);
+/// let solver = |lambda: i64| larsch_with_count(n, 0, |j, i, _| cost(j, i) + lambda)[n];
+/// let bound = a.iter().sum::<i64>().pow(2);
+/// for k in 1..=n {
+///     let expected = divide_and_conquer_dp(n, k, cost)[n];
+///     assert_eq!(alien_trick(k, -bound, bound, 1, solver), Some(expected));
+/// }
+/// ```
+///
+/// This function has a time complexity of O(T(solver) log(hi - lo)).
+pub fn alien_trick<T>(
+    k: usize,
+    lo: i64,
+    hi: i64,
+    step: T,
+    solver: impl Fn(T) -> (T, usize),
+) -> Option<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + From<i64>,
+{
+    assert!(lo <= hi);
+    let lambda = |t: i128| T::from(t as i64) * step;
+    let (mut value, count) = solver(lambda(lo as i128));
+    if count < k {
+        return None;
+    }
+    let (mut lo, mut hi) = (lo as i128, hi as i128 + 1);
+    while hi - lo > 1 {
+        let mid = lo + (hi - lo) / 2;
+        let (v, c) = solver(lambda(mid));
+        if c >= k {
+            (lo, value) = (mid, v);
+        } else {
+            hi = mid;
+        }
+    }
+    Some(value - lambda(lo) * T::from(k as i64))
+}
@@ -0,0 +1,239 @@
+use std::time::{Duration, Instant};
+/// Deterministic pseudo random generator (xoshiro256**) for metaheuristics.
+///
+/// # Example
+///
+/// ```
+/// use optim::anneal::Rng;
+/// let mut rng = Rng::new(1);
+/// let x = rng.range(10);
+/// assert!(x < 10);
+/// assert_eq!(Rng::new(1).range(10), x);
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct Rng {
+    s: [u64; 4],
+}
+impl Rng {
+    /// This is initializer of `Rng` by `seed`.
+    /// This function has a time complexity of O(1).
+    pub fn new(seed: u64) -> Self {
+        let mut x = seed;
+        let mut splitmix = || {
+            x = x.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut z = x;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            z ^ (z >> 31)
+        };
+        Self {
+            s: [splitmix(), splitmix(), splitmix(), splitmix()],
+        }
+    }
+    /// Return a uniformly random `u64`.
+    /// This function has a time complexity of O(1).
+    pub fn next_u64(&mut self) -> u64 {
+        let res = self.s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = self.s[1] << 17;
+        self.s[2] ^= self.s[0];
+        self.s[3] ^= self.s[1];
+        self.s[1] ^= self.s[2];
+        self.s[0] ^= self.s[3];
+        self.s[2] ^= t;
+        self.s[3] = self.s[3].rotate_left(45);
+        res
+    }
+    /// Return a random integer in `[0, n)`.
+    /// This function has a time complexity of O(1).
+    pub fn range(&mut self, n: usize) -> usize {
+        assert!(n > 0);
+        ((self.next_u64() as u128 * n as u128) >> 64) as usize
+    }
+    /// Return a uniformly random `f64` in `[0, 1)`.
+    /// This function has a time complexity of O(1).
+    pub fn f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+/// State of a minimization problem for local search.
+pub trait State: Clone {
+    type Move;
+    /// Return the cost to minimize.
+    fn cost(&self) -> f64;
+    /// Return a random move from `self`.
+    fn neighbor(&self, rng: &mut Rng) -> Self::Move;
+    /// Return the difference of the cost when `mv` is applied.
+    /// The default implementation applies and undoes `mv`,
+    /// so override it by an incremental calculation if possible.
+    fn delta(&mut self, mv: &Self::Move) -> f64 {
+        let cost = self.cost();
+        self.apply(mv);
+        let res = self.cost() - cost;
+        self.undo(mv);
+        res
+    }
+    /// Apply `mv` to `self`.
+    fn apply(&mut self, mv: &Self::Move);
+    /// Revert `mv` applied just before.
+    fn undo(&mut self, mv: &Self::Move);
+}
+/// Temperature as a function of the progress from 0 to 1.
+/// Zero temperature gives local search accepting only non-worsening moves.
+#[derive(Clone, Copy, Debug)]
+pub enum Schedule {
+    /// Linear interpolation from `start` to `end`.
+    Linear { start: f64, end: f64 },
+    /// Geometric interpolation from `start` to `end`, both of which must be positive.
+    Exponential { start: f64, end: f64 },
+    /// Temperature given by a function of the progress.
+    Custom(fn(f64) -> f64),
+}
+impl Schedule {
+    /// Return the temperature at progress `p`.
+    /// This function has a time complexity of O(1).
+    pub fn temperature(&self, p: f64) -> f64 {
+        match *self {
+            Schedule::Linear { start, end } => start + (end - start) * p,
+            Schedule::Exponential { start, end } => start * (end / start).powf(p),
+            Schedule::Custom(f) => f(p),
+        }
+    }
+}
+/// Budget of each run.
+#[derive(Clone, Copy, Debug)]
+pub enum Budget {
+    Iterations(u64),
+    /// Wall-clock time, which is not deterministic.
+    Time(Duration),
+}
+/// Options of simulated annealing.
+#[derive(Clone, Copy, Debug)]
+pub struct AnnealConfig {
+    pub schedule: Schedule,
+    pub budget: Budget,
+    /// The number of runs from the initial state after the first run.
+    pub restarts: usize,
+    pub seed: u64,
+    /// Record the trace every `trace_interval` iterations, or never if it is 0.
+    pub trace_interval: u64,
+}
+/// Record of simulated annealing.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TracePoint {
+    pub run: usize,
+    pub iteration: u64,
+    pub temperature: f64,
+    pub cost: f64,
+    pub best: f64,
+}
+/// Result of simulated annealing.
+#[derive(Clone, Debug)]
+pub struct AnnealResult<S> {
+    pub best: S,
+    pub cost: f64,
+    /// The total number of iterations.
+    pub iterations: u64,
+    /// The total number of accepted moves.
+    pub accepted: u64,
+    pub trace: Vec<TracePoint>,
+}
+/// Minimize the cost by simulated annealing from `init`.
+/// A move with difference `d` is accepted with probability `min(1, exp(-d / T))`.
+/// With `Budget::Iterations`, the result depends only on `init` and `config`.
+///
+/// # Example
+///
+/// ```
+/// use optim::anneal::{simulated_annealing, AnnealConfig, Budget, Rng, Schedule, State};
+/// #[derive(Clone)]
+/// struct X(i64);
+/// impl State for X {
+///     type Move = i64;
+///     fn cost(&self) -> f64 {
+///         ((self.0 - 37) * (self.0 - 37)) as f64
+///     }
+///     fn neighbor(&self, rng: &mut Rng) -> i64 {
+///         rng.range(11) as i64 - 5
+///     }
+///     fn apply(&mut self, mv: &i64) {
+///         self.0 += mv;
+///     }
+///     fn undo(&mut self, mv: &i64) {
+///         self.0 -= mv;
+///     }
+/// }
+/// let config = AnnealConfig {
+///     schedule: Schedule::Exponential { start: 100.0, end: 0.1 },
+///     budget: Budget::Iterations(10000),
+///     restarts: 1,
+///     seed: 42,
+///     trace_interval: 1000,
+/// };
+/// let res = simulated_annealing(X(0), &config);
+/// assert_eq!(res.best.0, 37);
+/// assert_eq!(res.iterations, 20000);
+/// assert_eq!(res.trace.len(), 20);
+/// ```
+///
+/// This function has a time complexity of O(iterations (T(neighbor) + T(delta) + T(apply))).
+pub fn simulated_annealing<S: State>(init: S, config: &AnnealConfig) -> AnnealResult<S> {
+    let mut rng = Rng::new(config.seed);
+    let mut best = init.clone();
+    let mut best_cost = init.cost();
+    let (mut iterations, mut accepted) = (0, 0);
+    let mut trace = vec![];
+    for run in 0..=config.restarts {
+        let start = Instant::now();
+        let mut state = init.clone();
+        let mut cost = state.cost();
+        let mut i = 0;
+        loop {
+            let p = match config.budget {
+                Budget::Iterations(n) => {
+                    if i >= n {
+                        break;
+                    }
+                    i as f64 / n as f64
+                }
+                Budget::Time(t) => {
+                    let p = start.elapsed().as_secs_f64() / t.as_secs_f64();
+                    if p >= 1.0 {
+                        break;
+                    }
+                    p
+                }
+            };
+            let temperature = config.schedule.temperature(p);
+            let mv = state.neighbor(&mut rng);
+            let d = state.delta(&mv);
+            if d <= 0.0 || (temperature > 0.0 && rng.f64() < (-d / temperature).exp()) {
+                state.apply(&mv);
+                cost += d;
+                accepted += 1;
+                if cost < best_cost {
+                    best = state.clone();
+                    best_cost = cost;
+                }
+            }
+            i += 1;
+            if config.trace_interval > 0 && i % config.trace_interval == 0 {
+                trace.push(TracePoint {
+                    run,
+                    iteration: i,
+                    temperature,
+                    cost,
+                    best: best_cost,
+                });
+            }
+        }
+        iterations += i;
+    }
+    AnnealResult {
+        cost: best.cost(),
+        best,
+        iterations,
+        accepted,
+        trace,
+    }
+}
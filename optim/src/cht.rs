@@ -0,0 +1,214 @@
+use geometry::Vector;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+/// Return `a x + b` calculated in i128.
+fn eval(a: i64, b: i64, x: i64) -> i128 {
+    a as i128 * x as i128 + b as i128
+}
+/// Convex hull trick for lines added in non-increasing order of slopes.
+/// It answers the minimum of `a x + b`; negate `a`, `b` and the answer for the maximum.
+/// Lines are regarded as points `(a, b)`, and the hull is checked by `Vector::ccw`.
+///
+/// # Example
+///
+/// ```
+/// use optim::cht::MonotoneCHT;
+/// let mut cht = MonotoneCHT::new();
+/// cht.add(2, 0);
+/// cht.add(0, 3);
+/// cht.add(-1, 10);
+/// assert_eq!(cht.query(1), Some(2));
+/// assert_eq!(cht.query(5), Some(3));
+/// assert_eq!(cht.query(9), Some(1));
+/// ```
+///
+#[derive(Clone, Debug, Default)]
+pub struct MonotoneCHT {
+    lines: VecDeque<Vector>,
+}
+impl MonotoneCHT {
+    /// This is initializer of `MonotoneCHT` with no lines.
+    /// This function has a time complexity of O(1).
+    pub fn new() -> Self {
+        Self {
+            lines: VecDeque::new(),
+        }
+    }
+    /// Return the number of lines on the hull.
+    /// This function has a time complexity of O(1).
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+    /// Return whether there is no line.
+    /// This function has a time complexity of O(1).
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+    /// Add line `a x + b`, where `a` must be less than or equal to all slopes added before.
+    /// This function has a time complexity of amortized O(1).
+    pub fn add(&mut self, a: i64, b: i64) {
+        let p = Vector::new(a, b);
+        if let Some(last) = self.lines.back() {
+            assert!(a <= last.x());
+            if a == last.x() {
+                if b >= last.y() {
+                    return;
+                }
+                self.lines.pop_back();
+            }
+        }
+        while self.lines.len() >= 2 {
+            let n = self.lines.len();
+            let (p1, p2) = (self.lines[n - 2], self.lines[n - 1]);
+            if p1.ccw(&p2, &p) == Ordering::Less {
+                break;
+            }
+            self.lines.pop_back();
+        }
+        self.lines.push_back(p);
+    }
+    /// Return the minimum of `a x + b` over the lines.
+    /// If there is no line, return `None`.
+    /// This function has a time complexity of O(log n).
+    pub fn query(&self, x: i64) -> Option<i128> {
+        let (mut lo, mut hi) = (0, self.lines.len().checked_sub(1)?);
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            if self.value(mid, x) > self.value(mid + 1, x) {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        Some(self.value(lo, x))
+    }
+    /// Return the minimum of `a x + b` over the lines,
+    /// where `x` must be greater than or equal to all `x` queried before by this function.
+    /// If there is no line, return `None`.
+    /// This function has a time complexity of amortized O(1).
+    pub fn query_monotone(&mut self, x: i64) -> Option<i128> {
+        while self.lines.len() >= 2 && self.value(0, x) >= self.value(1, x) {
+            self.lines.pop_front();
+        }
+        self.lines.front().map(|p| eval(p.x(), p.y(), x))
+    }
+    fn value(&self, i: usize, x: i64) -> i128 {
+        eval(self.lines[i].x(), self.lines[i].y(), x)
+    }
+}
+/// Fully dynamic line container.
+/// It answers the minimum of `a x + b`; negate `a`, `b` and the answer for the maximum.
+///
+/// # Example
+///
+/// ```
+/// use optim::cht::LineContainer;
+/// let mut lc = LineContainer::new();
+/// lc.add(-1, 10);
+/// lc.add(2, 0);
+/// lc.add(0, 3);
+/// assert_eq!(lc.query(1), Some(2));
+/// assert_eq!(lc.query(5), Some(3));
+/// assert_eq!(lc.query(9), Some(1));
+/// ```
+///
+#[derive(Clone, Debug, Default)]
+pub struct LineContainer {
+    /// Map from slope `k` of `-a x - b` to `(m, p)` where `m = -b`
+    /// and `p` is the last `x` at which the line is the maximum.
+    lines: BTreeMap<i128, (i128, i128)>,
+    ends: BTreeSet<(i128, i128)>,
+}
+impl LineContainer {
+    /// This is initializer of `LineContainer` with no lines.
+    /// This function has a time complexity of O(1).
+    pub fn new() -> Self {
+        Self {
+            lines: BTreeMap::new(),
+            ends: BTreeSet::new(),
+        }
+    }
+    /// Return the number of lines on the hull.
+    /// This function has a time complexity of O(1).
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+    /// Return whether there is no line.
+    /// This function has a time complexity of O(1).
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+    /// Add line `a x + b`.
+    /// This function has a time complexity of amortized O(log n).
+    pub fn add(&mut self, a: i64, b: i64) {
+        let (k, m) = (-(a as i128), -(b as i128));
+        if let Some(&(m0, _)) = self.lines.get(&k) {
+            if m0 >= m {
+                return;
+            }
+            self.remove(k);
+        }
+        self.lines.insert(k, (m, i128::MAX));
+        while let Some(z) = self.next(k) {
+            let p = self.intersect(k, z);
+            if p < self.lines[&z].1 {
+                break;
+            }
+            self.remove(z);
+        }
+        let p = self.next(k).map_or(i128::MAX, |z| self.intersect(k, z));
+        self.set_end(k, p);
+        let Some(mut x) = self.prev(k) else {
+            return;
+        };
+        let p = self.intersect(x, k);
+        if p >= self.lines[&k].1 {
+            self.remove(k);
+            let p = self.next(x).map_or(i128::MAX, |z| self.intersect(x, z));
+            self.set_end(x, p);
+            return;
+        }
+        self.set_end(x, p);
+        while let Some(w) = self.prev(x) {
+            if self.lines[&w].1 < self.lines[&x].1 {
+                break;
+            }
+            self.remove(x);
+            let p = self.intersect(w, k);
+            self.set_end(w, p);
+            x = w;
+        }
+    }
+    /// Return the minimum of `a x + b` over the lines.
+    /// If there is no line, return `None`.
+    /// This function has a time complexity of O(log n).
+    pub fn query(&self, x: i64) -> Option<i128> {
+        let &(_, k) = self.ends.range((x as i128, i128::MIN)..).next()?;
+        Some(-(k * x as i128 + self.lines[&k].0))
+    }
+    /// Return the last `x` at which line `x` is not less than line `y`, where `x < y` as slopes.
+    fn intersect(&self, x: i128, y: i128) -> i128 {
+        let (mx, my) = (self.lines[&x].0, self.lines[&y].0);
+        (mx - my).div_euclid(y - x)
+    }
+    fn next(&self, k: i128) -> Option<i128> {
+        use std::ops::Bound::{Excluded, Unbounded};
+        self.lines
+            .range((Excluded(k), Unbounded))
+            .next()
+            .map(|e| *e.0)
+    }
+    fn prev(&self, k: i128) -> Option<i128> {
+        self.lines.range(..k).next_back().map(|e| *e.0)
+    }
+    fn set_end(&mut self, k: i128, p: i128) {
+        let e = self.lines.get_mut(&k).unwrap();
+        self.ends.remove(&(e.1, k));
+        e.1 = p;
+        self.ends.insert((p, k));
+    }
+    fn remove(&mut self, k: i128) {
+        let (_, p) = self.lines.remove(&k).unwrap();
+        self.ends.remove(&(p, k));
+    }
+}
@@ -0,0 +1,111 @@
+use algebra::Bounded;
+use matrix::Matrix;
+use std::ops::{Neg, Sub};
+/// Result of the assignment problem.
+#[derive(Clone, Debug)]
+pub struct Assignment<T> {
+    /// The total cost of the assignment.
+    pub cost: T,
+    /// `assign[i]` is the column assigned to row `i`.
+    pub assign: Vec<Option<usize>>,
+    /// The potentials of rows.
+    pub u: Vec<T>,
+    /// The potentials of columns.
+    /// In minimization `u_i + v_j <= c_ij` holds and the equality holds on the assignment.
+    /// In maximization the inequality is reversed.
+    pub v: Vec<T>,
+}
+/// Solve the assignment problem of `h` x `w` cost matrix `c` by Hungarian algorithm.
+/// `min(h, w)` pairs are assigned.
+///
+/// # Example
+///
+/// ```
+/// use matrix::Matrix;
+/// use optim::hungarian::hungarian;
+/// let c = Matrix::new(3, 3, &[vec![4, 1, 3], vec![2, 0, 5], vec![3, 2, 2]]);
+/// let res = hungarian(&c, false);
+/// assert_eq!(res.cost, 5);
+/// assert_eq!(res.assign, vec![Some(1), Some(0), Some(2)]);
+/// ```
+///
+/// This function has a time complexity of O(min(h, w)^2 max(h, w)).
+pub fn hungarian<T: Bounded + Sub<Output = T> + Neg<Output = T>>(
+    c: &Matrix<T>,
+    maximize: bool,
+) -> Assignment<T> {
+    let (h, w) = (c.h(), c.w());
+    let is_transposed = h > w;
+    let (n, m) = if is_transposed { (w, h) } else { (h, w) };
+    let cost = |i: usize, j: usize| {
+        let x = if is_transposed { c[j][i] } else { c[i][j] };
+        if maximize { -x } else { x }
+    };
+    let mut u = vec![T::ZERO; n + 1];
+    let mut v = vec![T::ZERO; m + 1];
+    let mut p = vec![0; m + 1];
+    let mut way = vec![0; m + 1];
+    for i in 1..=n {
+        p[0] = i;
+        let mut j0 = 0;
+        let mut minv = vec![T::MAX; m + 1];
+        let mut used = vec![false; m + 1];
+        loop {
+            used[j0] = true;
+            let i0 = p[j0];
+            let mut delta = T::MAX;
+            let mut j1 = 0;
+            for j in (1..=m).filter(|&j| !used[j]) {
+                let cur = cost(i0 - 1, j - 1) - u[i0] - v[j];
+                if cur < minv[j] {
+                    minv[j] = cur;
+                    way[j] = j0;
+                }
+                if minv[j] < delta {
+                    delta = minv[j];
+                    j1 = j;
+                }
+            }
+            for j in 0..=m {
+                if used[j] {
+                    u[p[j]] = u[p[j]] + delta;
+                    v[j] = v[j] - delta;
+                } else {
+                    minv[j] = minv[j] - delta;
+                }
+            }
+            j0 = j1;
+            if p[j0] == 0 {
+                break;
+            }
+        }
+        while j0 != 0 {
+            let j1 = way[j0];
+            p[j0] = p[j1];
+            j0 = j1;
+        }
+    }
+    let sign = |x: T| if maximize { -x } else { x };
+    let mut u = u[1..].iter().map(|&x| sign(x)).collect::<Vec<_>>();
+    let mut v = v[1..].iter().map(|&x| sign(x)).collect::<Vec<_>>();
+    let mut assign = vec![None; h];
+    let mut total = T::ZERO;
+    for j in (1..=m).filter(|&j| p[j] != 0) {
+        let (r, s) = if is_transposed {
+            (j - 1, p[j] - 1)
+        } else {
+            (p[j] - 1, j - 1)
+        };
+        assign[r] = Some(s);
+        total = total + c[r][s];
+    }
+    if is_transposed {
+        std::mem::swap(&mut u, &mut v);
+    }
+    Assignment {
+        cost: total,
+        assign,
+        u,
+        v,
+    }
+}
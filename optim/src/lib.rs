@@ -0,0 +1,9 @@
+pub mod alien;
+pub mod anneal;
+pub mod cht;
+pub mod hungarian;
+pub mod lichao;
+pub mod mip;
+pub mod monge;
+pub mod search;
+pub mod simplex;
@@ -0,0 +1,126 @@
+/// Li Chao tree over given coordinates.
+/// It answers the minimum of `a x + b`; negate `a`, `b` and the answer for the maximum.
+/// Values are compared in i128, so any i64 lines and coordinates are allowed.
+///
+/// # Example
+///
+/// ```
+/// use optim::lichao::LiChaoTree;
+/// let mut lct = LiChaoTree::new(&[0, 1, 2, 3, 4]);
+/// lct.add_line(1, 0);
+/// lct.add_segment(2..4, -1, 1);
+/// assert_eq!(lct.query(1), Some(1));
+/// assert_eq!(lct.query(3), Some(-2));
+/// assert_eq!(lct.query(4), Some(4));
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct LiChaoTree {
+    n: usize,
+    m: usize,
+    xs: Vec<i64>,
+    lines: Vec<Option<(i64, i64)>>,
+}
+use std::ops::RangeBounds;
+impl LiChaoTree {
+    /// This is initializer of `LiChaoTree` answering queries at `xs`.
+    /// This function has a time complexity of O(n log n).
+    pub fn new(xs: &[i64]) -> Self {
+        let mut xs = xs.to_vec();
+        xs.sort_unstable();
+        xs.dedup();
+        let n = xs.len();
+        let m = n.next_power_of_two();
+        let last = xs.last().copied().unwrap_or(0);
+        xs.resize(m, last);
+        Self {
+            n,
+            m,
+            xs,
+            lines: vec![None; 2 * m],
+        }
+    }
+    /// Add line `a x + b`.
+    /// This function has a time complexity of O(log n).
+    pub fn add_line(&mut self, a: i64, b: i64) {
+        self.insert(1, (a, b));
+    }
+    /// Add line segment `a x + b` for `x` in `range`.
+    /// This function has a time complexity of O(log^2 n).
+    pub fn add_segment(&mut self, range: impl RangeBounds<i64>, a: i64, b: i64) {
+        use std::ops::Bound::{Excluded, Included, Unbounded};
+        let xs = &self.xs[..self.n];
+        let mut l = match range.start_bound() {
+            Unbounded => 0,
+            Included(x) => xs.partition_point(|v| v < x),
+            Excluded(x) => xs.partition_point(|v| v <= x),
+        } + self.m;
+        let mut r = match range.end_bound() {
+            Unbounded => self.n,
+            Included(x) => xs.partition_point(|v| v <= x),
+            Excluded(x) => xs.partition_point(|v| v < x),
+        } + self.m;
+        while l < r {
+            if l & 1 == 1 {
+                self.insert(l, (a, b));
+                l += 1;
+            }
+            if r & 1 == 1 {
+                r -= 1;
+                self.insert(r, (a, b));
+            }
+            l >>= 1;
+            r >>= 1;
+        }
+    }
+    /// Return the minimum of `a x + b` over the lines and segments containing `x`,
+    /// where `x` must be one of the coordinates.
+    /// If there is none, return `None`.
+    /// This function has a time complexity of O(log n).
+    pub fn query(&self, x: i64) -> Option<i128> {
+        let i = self.xs[..self.n]
+            .binary_search(&x)
+            .expect("x is not a coordinate");
+        std::iter::successors(Some(i + self.m), |k| Some(k >> 1))
+            .take_while(|k| *k > 0)
+            .filter_map(|k| self.lines[k].map(|(a, b)| eval(a, b, x)))
+            .min()
+    }
+    /// Insert `line` into the subtree of node `k`.
+    /// This function has a time complexity of O(log n).
+    fn insert(&mut self, mut k: usize, mut line: (i64, i64)) {
+        let depth = k.ilog2();
+        let width = self.m >> depth;
+        let mut lo = (k - (1 << depth)) * width;
+        let mut hi = lo + width;
+        loop {
+            let Some(cur) = self.lines[k] else {
+                self.lines[k] = Some(line);
+                return;
+            };
+            let mid = (lo + hi) / 2;
+            let f = |(a, b): (i64, i64), i: usize| eval(a, b, self.xs[i]);
+            let (cur, line_) = if f(line, mid) < f(cur, mid) {
+                (line, cur)
+            } else {
+                (cur, line)
+            };
+            self.lines[k] = Some(cur);
+            line = line_;
+            if hi - lo == 1 {
+                return;
+            }
+            if f(line, lo) < f(cur, lo) {
+                (k, hi) = (2 * k, mid);
+            } else if f(line, hi - 1) < f(cur, hi - 1) {
+                (k, lo) = (2 * k + 1, mid);
+            } else {
+                return;
+            }
+        }
+    }
+}
+/// Return `a x + b` calculated in i128.
+fn eval(a: i64, b: i64, x: i64) -> i128 {
+    a as i128 * x as i128 + b as i128
+}
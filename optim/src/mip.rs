@@ -0,0 +1,232 @@
+use super::simplex::{LPNum, LPResult, Tableau};
+use matrix::Matrix;
+use std::collections::BinaryHeap;
+use std::time::{Duration, Instant};
+/// Budget and options of branch-and-bound.
+#[derive(Clone, Debug)]
+pub struct MIPConfig {
+    /// The maximum number of nodes to solve.
+    pub nodes: usize,
+    /// The wall-clock time limit.
+    pub time: Option<Duration>,
+    /// The maximum number of Gomory cuts added at the root.
+    /// Cuts are added only if every variable is integral and `A` and `b` are integral.
+    pub cuts: usize,
+}
+impl Default for MIPConfig {
+    fn default() -> Self {
+        Self {
+            nodes: usize::MAX,
+            time: None,
+            cuts: 0,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MIPStatus {
+    Optimal,
+    Infeasible,
+    /// The LP relaxation at the root is unbounded.
+    /// The integer problem itself may be unbounded or infeasible, which is not checked.
+    Unbounded,
+    /// The budget is exhausted before proving optimality.
+    Interrupted,
+}
+/// Result of mixed integer programming.
+#[derive(Clone, Debug)]
+pub struct MIPResult<T> {
+    pub status: MIPStatus,
+    /// The best objective value and solution found.
+    pub incumbent: Option<(T, Vec<T>)>,
+    /// An upper bound of the optimal value.
+    pub bound: Option<T>,
+    /// The number of solved nodes.
+    pub nodes: usize,
+}
+impl<T: LPNum> MIPResult<T> {
+    /// Return `bound - incumbent`, which is zero if `status` is `Optimal`.
+    /// This function has a time complexity of O(1).
+    pub fn gap(&self) -> Option<T> {
+        match (&self.incumbent, &self.bound) {
+            (Some((v, _)), Some(b)) => Some(*b - *v),
+            _ => None,
+        }
+    }
+}
+/// Node of branch-and-bound ordered by its bound.
+/// A bound which is not comparable with itself, such as NaN, is regarded as the smallest.
+struct Node<T> {
+    bound: T,
+    id: usize,
+    bounds: Vec<(usize, bool, T)>,
+}
+impl<T: PartialOrd> PartialEq for Node<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == std::cmp::Ordering::Equal
+    }
+}
+impl<T: PartialOrd> Eq for Node<T> {}
+impl<T: PartialOrd> PartialOrd for Node<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl<T: PartialOrd> Ord for Node<T> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let nan = |x: &T| x.partial_cmp(x).is_none();
+        nan(&other.bound)
+            .cmp(&nan(&self.bound))
+            .then_with(|| {
+                self.bound
+                    .partial_cmp(&other.bound)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .then_with(|| other.id.cmp(&self.id))
+    }
+}
+/// Solve `max c x s.t. A x <= b, x >= 0, x_j is integer if is_integer[j]`
+/// by best-bound branch-and-bound.
+///
+/// # Example
+///
+/// ```
+/// use matrix::Matrix;
+/// use optim::mip::{branch_and_bound, MIPConfig, MIPStatus};
+/// let a = Matrix::new(2, 2, &[vec![-2.0, 2.0], vec![8.0, 2.0]]);
+/// let res = branch_and_bound(&a, &[1.0, 17.0], &[0.0, 1.0], &[true, true], &MIPConfig::default());
+/// assert_eq!(res.status, MIPStatus::Optimal);
+/// let (value, x) = res.incumbent.unwrap();
+/// assert!((value - 1.0).abs() < 1e-9 && (x[0] - 1.0).abs() < 1e-9);
+/// ```
+///
+pub fn branch_and_bound<T: LPNum>(
+    a: &Matrix<T>,
+    b: &[T],
+    c: &[T],
+    is_integer: &[bool],
+    config: &MIPConfig,
+) -> MIPResult<T> {
+    let n = c.len();
+    assert_eq!(is_integer.len(), n);
+    let start = Instant::now();
+    let is_integral = |x: &T| {
+        let f = *x - x.floor();
+        !f.is_pos() || !(T::from(1) - f).is_pos()
+    };
+    let mut rows = a.iter().map(|r| r.to_vec()).collect::<Vec<_>>();
+    let mut rhs = b.to_vec();
+    let mut tableau = Tableau::new(a, b, c);
+    let root = match tableau.solve() {
+        LPResult::Optimal { value, .. } => value,
+        status => {
+            return MIPResult {
+                status: if matches!(status, LPResult::Infeasible) {
+                    MIPStatus::Infeasible
+                } else {
+                    MIPStatus::Unbounded
+                },
+                incumbent: None,
+                bound: None,
+                nodes: 1,
+            };
+        }
+    };
+    if config.cuts > 0
+        && is_integer.iter().all(|f| *f)
+        && a.iter().flatten().chain(b.iter()).all(is_integral)
+    {
+        for (cut, r) in tableau.gomory_cuts(a, b, config.cuts) {
+            rows.push(cut);
+            rhs.push(r);
+        }
+    }
+    let mut heap = BinaryHeap::new();
+    heap.push(Node {
+        bound: root,
+        id: 0,
+        bounds: vec![],
+    });
+    let mut id = 1;
+    let mut nodes = 0;
+    let mut incumbent: Option<(T, Vec<T>)> = None;
+    let mut interrupted = false;
+    while let Some(node) = heap.pop() {
+        if let Some((v, _)) = &incumbent
+            && !(node.bound - *v).is_pos()
+        {
+            continue;
+        }
+        if nodes >= config.nodes || config.time.is_some_and(|t| start.elapsed() >= t) {
+            heap.push(node);
+            interrupted = true;
+            break;
+        }
+        nodes += 1;
+        let m = rows.len() + node.bounds.len();
+        let mut b = rhs.clone();
+        b.extend(
+            node.bounds
+                .iter()
+                .map(|&(_, is_upper, v)| if is_upper { v } else { -v }),
+        );
+        let a = Matrix::from_fn(m, n, |i, j| {
+            if i < rows.len() {
+                rows[i][j]
+            } else {
+                let (k, is_upper, _) = node.bounds[i - rows.len()];
+                if k != j {
+                    T::from(0)
+                } else if is_upper {
+                    T::from(1)
+                } else {
+                    -T::from(1)
+                }
+            }
+        });
+        let LPResult::Optimal { value, primal, .. } = Tableau::new(&a, &b, c).solve() else {
+            continue;
+        };
+        if let Some((v, _)) = &incumbent
+            && !(value - *v).is_pos()
+        {
+            continue;
+        }
+        match (0..n).find(|&j| is_integer[j] && !is_integral(&primal[j])) {
+            None => incumbent = Some((value, primal)),
+            Some(j) => {
+                let f = primal[j].floor();
+                for (is_upper, v) in [(true, f), (false, f + T::from(1))] {
+                    let mut bounds = node.bounds.clone();
+                    bounds.push((j, is_upper, v));
+                    heap.push(Node {
+                        bound: value,
+                        id,
+                        bounds,
+                    });
+                    id += 1;
+                }
+            }
+        }
+    }
+    let bound = if interrupted {
+        let top = heap.peek().map(|node| node.bound);
+        match (top, &incumbent) {
+            (Some(t), Some((v, _))) => Some(if t < *v { *v } else { t }),
+            (t, v) => t.or(v.as_ref().map(|v| v.0)),
+        }
+    } else {
+        incumbent.as_ref().map(|v| v.0)
+    };
+    MIPResult {
+        status: if interrupted {
+            MIPStatus::Interrupted
+        } else if incumbent.is_some() {
+            MIPStatus::Optimal
+        } else {
+            MIPStatus::Infeasible
+        },
+        incumbent,
+        bound,
+        nodes,
+    }
+}
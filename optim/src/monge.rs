@@ -0,0 +1,271 @@
+use algebra::Bounded;
+/// Return the leftmost column of the minimum in each row of a totally monotone `h` x `w` matrix
+/// `f(i, j)` by SMAWK algorithm.
+///
+/// # Example
+///
+/// ```
+/// use optim::monge::smawk;
+/// let a = [[3, 2, 4], [4, 2, 3], [6, 4, 3]];
+/// assert_eq!(smawk(3, 3, |i, j| a[i][j]), vec![1, 1, 2]);
+/// ```
+///
+/// This function has a time complexity of O(h + w).
+pub fn smawk<T: PartialOrd>(h: usize, w: usize, f: impl Fn(usize, usize) -> T) -> Vec<usize> {
+    assert!(h == 0 || w > 0);
+    let mut res = vec![0; h];
+    let rows = (0..h).collect::<Vec<_>>();
+    let cols = (0..w).collect::<Vec<_>>();
+    smawk_rec(&rows, &cols, &f, &mut res);
+    res
+}
+fn smawk_rec<T: PartialOrd>(
+    rows: &[usize],
+    cols: &[usize],
+    f: &impl Fn(usize, usize) -> T,
+    res: &mut [usize],
+) {
+    if rows.is_empty() {
+        return;
+    }
+    let mut st: Vec<usize> = Vec::with_capacity(rows.len());
+    for &c in cols {
+        while let Some(&last) = st.last() {
+            let r = rows[st.len() - 1];
+            if f(r, c) < f(r, last) {
+                st.pop();
+            } else {
+                break;
+            }
+        }
+        if st.len() < rows.len() {
+            st.push(c);
+        }
+    }
+    let odd = rows.iter().skip(1).step_by(2).copied().collect::<Vec<_>>();
+    smawk_rec(&odd, &st, f, res);
+    let mut k = 0;
+    for i in (0..rows.len()).step_by(2) {
+        let r = rows[i];
+        let end = if i + 1 < rows.len() {
+            res[rows[i + 1]]
+        } else {
+            st[st.len() - 1]
+        };
+        let mut best = st[k];
+        while st[k] != end {
+            k += 1;
+            if f(r, st[k]) < f(r, best) {
+                best = st[k];
+            }
+        }
+        res[r] = best;
+    }
+}
+/// Return the leftmost column of the minimum in each row of an `h` x `w` matrix `f(i, j)`
+/// whose leftmost argmin is non-decreasing, by divide and conquer.
+///
+/// # Example
+///
+/// ```
+/// use optim::monge::monotone_minima;
+/// let a = [[3, 2, 4], [4, 2, 3], [6, 4, 3]];
+/// assert_eq!(monotone_minima(3, 3, |i, j| a[i][j]), vec![1, 1, 2]);
+/// ```
+///
+/// This function has a time complexity of O((h + w) log h).
+pub fn monotone_minima<T: PartialOrd>(
+    h: usize,
+    w: usize,
+    f: impl Fn(usize, usize) -> T,
+) -> Vec<usize> {
+    assert!(h == 0 || w > 0);
+    let mut res = vec![0; h];
+    let mut stack = vec![(0, h, 0, w)];
+    while let Some((lo, hi, l, r)) = stack.pop() {
+        if lo == hi {
+            continue;
+        }
+        let mid = (lo + hi) / 2;
+        let mut best = l;
+        let mut value = f(mid, l);
+        for j in l + 1..r {
+            let v = f(mid, j);
+            if v < value {
+                (best, value) = (j, v);
+            }
+        }
+        res[mid] = best;
+        stack.push((lo, mid, l, best + 1));
+        stack.push((mid + 1, hi, best, r));
+    }
+    res
+}
+/// Return `dp[k][i]` for `i` in `0..=n`, where `dp[0][0] = 0` and
+/// `dp[k][i] = min_{j < i} dp[k - 1][j] + cost(j, i)`, that is,
+/// the minimum cost to divide `[0, i)` into `k` non-empty segments `[j, i)`.
+/// `cost` must be Monge, and `T::MAX` is returned if there is no such division.
+///
+/// # Example
+///
+/// ```
+/// use optim::monge::divide_and_conquer_dp;
+/// let a = [1i64, 5, 2, 4];
+/// let cost = |j: usize, i: usize| {
+///     let s: i64 = a[j..i].iter().sum();
+///     s * s
+/// };
+/// assert_eq!(divide_and_conquer_dp(4, 2, cost)[4], 36 + 36);
+/// ```
+///
+/// This function has a time complexity of O(k n log n).
+pub fn divide_and_conquer_dp<T: Bounded>(
+    n: usize,
+    k: usize,
+    cost: impl Fn(usize, usize) -> T,
+) -> Vec<T> {
+    let mut dp = vec![T::MAX; n + 1];
+    dp[0] = T::ZERO;
+    for t in 1..=k {
+        let mut next = vec![T::MAX; n + 1];
+        if t <= n {
+            let argmin = monotone_minima(n + 1 - t, n + 1 - t, |i, j| {
+                let (i, j) = (i + t, j + t - 1);
+                if j < i && dp[j] != T::MAX {
+                    (false, dp[j] + cost(j, i))
+                } else {
+                    (true, T::ZERO)
+                }
+            });
+            for (i, j) in argmin.into_iter().enumerate() {
+                let (i, j) = (i + t, j + t - 1);
+                next[i] = dp[j] + cost(j, i);
+            }
+        }
+        dp = next;
+    }
+    dp
+}
+/// Return `dp[0][n]` by Knuth-Yao speedup, where `dp[i][i + 1] = 0` and
+/// `dp[i][j] = w(i, j) + min_{i < m < j} dp[i][m] + dp[m][j]`.
+/// `w` must be Monge and monotone on the lattice of intervals.
+///
+/// # Example
+///
+/// ```
+/// use optim::monge::knuth_yao;
+/// let a = [3i64, 1, 4, 1, 5];
+/// let w = |i: usize, j: usize| a[i..j].iter().sum::<i64>();
+/// assert_eq!(knuth_yao(5, w), 32);
+/// ```
+///
+/// This function has a time complexity of O(n^2).
+pub fn knuth_yao<T: Bounded>(n: usize, w: impl Fn(usize, usize) -> T) -> T {
+    assert!(n > 0);
+    let mut dp = vec![vec![T::ZERO; n + 1]; n + 1];
+    let mut opt = vec![vec![0; n + 1]; n + 1];
+    for i in 0..n {
+        opt[i][i + 1] = i + 1;
+    }
+    for len in 2..=n {
+        for i in 0..=n - len {
+            let j = i + len;
+            let (lo, hi) = (opt[i][j - 1].max(i + 1), opt[i + 1][j].min(j - 1));
+            let mut best = T::MAX;
+            for (m, row) in dp.iter().enumerate().take(hi + 1).skip(lo) {
+                let v = dp[i][m] + row[j];
+                if v < best {
+                    (best, opt[i][j]) = (v, m);
+                }
+            }
+            dp[i][j] = best + w(i, j);
+        }
+    }
+    dp[0][n]
+}
+/// Return `dp[i]` for `i` in `0..=n`, where `dp[0] = init` and
+/// `dp[i] = min_{j < i} dp[j] + cost(j, i, dp[j])`, by a simplified LARSCH algorithm.
+/// `dp[j] + cost(j, i, dp[j])` must be Monge as a matrix indexed by `(j, i)`.
+/// `dp[j]` is fixed before `cost(j, i, dp[j])` is called, so the recurrence can be online.
+///
+/// # Example
+///
+/// ```
+/// use optim::monge::larsch;
+/// let a = [1i64, 5, 2, 4];
+/// let cost = |j: usize, i: usize, _: &i64| {
+///     let s: i64 = a[j..i].iter().sum();
+///     s * s + 10
+/// };
+/// assert_eq!(larsch(4, 0, cost), vec![0, 11, 46, 60, 86]);
+/// ```
+///
+/// This function has a time complexity of O(n log n).
+pub fn larsch<T: Bounded>(n: usize, init: T, cost: impl Fn(usize, usize, &T) -> T) -> Vec<T> {
+    larsch_with_count(n, init, cost)
+        .into_iter()
+        .map(|v| v.0)
+        .collect()
+}
+/// Return `(dp[i], count[i])` for `i` in `0..=n`, where `dp` is the same as `larsch` and
+/// `count[i]` is the maximum number of transitions among the optimal paths to `i`.
+/// This is the inner solver of `alien::alien_trick` with `cost` including the penalty.
+///
+/// # Example
+///
+/// ```
+/// use optim::monge::larsch_with_count;
+/// let a = [1i64, 5, 2, 4];
+/// let cost = |j: usize, i: usize, _: &i64| {
+///     let s: i64 = a[j..i].iter().sum();
+///     s * s + 10
+/// };
+/// // Both of 3 and 4 segments cost 86.
+/// let res = larsch_with_count(4, 0, cost);
+/// assert_eq!(res[4], (86, 4));
+/// ```
+///
+/// This function has a time complexity of O(n log n).
+pub fn larsch_with_count<T: Bounded>(
+    n: usize,
+    init: T,
+    cost: impl Fn(usize, usize, &T) -> T,
+) -> Vec<(T, usize)> {
+    let mut dp = vec![(T::MAX, 0); n + 1];
+    let mut argmin = vec![0; n + 1];
+    dp[0] = (init, 0);
+    if n == 0 {
+        return dp;
+    }
+    let check = |dp: &mut [(T, usize)], argmin: &mut [usize], i: usize, j: usize| {
+        let v = (dp[j].0 + cost(j, i, &dp[j].0), dp[j].1 + 1);
+        if v.0 < dp[i].0 || (v.0 == dp[i].0 && v.1 > dp[i].1) {
+            (dp[i], argmin[i]) = (v, j);
+        }
+    };
+    check(&mut dp, &mut argmin, n, 0);
+    let mut stack = vec![(0, n, false)];
+    while let Some((l, r, is_post)) = stack.pop() {
+        let m = (l + r) / 2;
+        if is_post {
+            for j in l + 1..=m {
+                check(&mut dp, &mut argmin, r, j);
+            }
+            if r - m > 1 {
+                stack.push((m, r, false));
+            }
+            continue;
+        }
+        if r - l <= 1 {
+            continue;
+        }
+        for j in argmin[l]..=argmin[r] {
+            if j < m {
+                check(&mut dp, &mut argmin, m, j);
+            }
+        }
+        stack.push((l, r, true));
+        stack.push((l, m, false));
+    }
+    dp
+}
@@ -0,0 +1,182 @@
+/// Return the leftmost argmin `x` in `[lo, hi]` and `f(x)` by Fibonacci search,
+/// where `f` is strictly decreasing and then non-decreasing.
+///
+/// # Example
+///
+/// ```
+/// use optim::search::argmin_unimodal_int;
+/// assert_eq!(argmin_unimodal_int(-10, 10, |x| (x - 3) * (x - 3)), (3, 0));
+/// assert_eq!(argmin_unimodal_int(0, 10, |x| (x - 3).abs().max(1)), (2, 1));
+/// ```
+///
+/// This function has a time complexity of O(log(hi - lo)) evaluations of `f`.
+pub fn argmin_unimodal_int<T: PartialOrd>(lo: i64, hi: i64, f: impl Fn(i64) -> T) -> (i64, T) {
+    assert!(lo <= hi);
+    let n = hi as i128 - lo as i128 + 1;
+    let mut fib = vec![1i128, 1];
+    while fib[fib.len() - 1] < n + 1 {
+        fib.push(fib[fib.len() - 1] + fib[fib.len() - 2]);
+    }
+    let mut k = fib.len() - 1;
+    if k < 3 {
+        return (lo, f(lo));
+    }
+    // Points greater than `hi` are regarded as infinity.
+    let eval = |x: i128| (x <= hi as i128).then(|| f(x as i64));
+    let le = |p: &Option<T>, q: &Option<T>| match (p, q) {
+        (Some(p), Some(q)) => p <= q,
+        (_, q) => q.is_none(),
+    };
+    let mut a = lo as i128 - 1;
+    let (mut c, mut d) = (a + fib[k - 2], a + fib[k - 1]);
+    let (mut fc, mut fd) = (eval(c), eval(d));
+    loop {
+        k -= 1;
+        if le(&fc, &fd) {
+            if k < 3 {
+                return (c as i64, fc.unwrap());
+            }
+            (d, fd) = (c, fc);
+            c = a + fib[k - 2];
+            fc = eval(c);
+        } else {
+            a = c;
+            if k < 3 {
+                return (d as i64, fd.unwrap());
+            }
+            (c, fc) = (d, fd);
+            d = a + fib[k - 1];
+            fd = eval(d);
+        }
+    }
+}
+/// Return an approximate argmin `x` in `[lo, hi]` and `f(x)` by golden-section search,
+/// where `f` is unimodal.
+/// The search stops when the interval is shorter than `eps`.
+///
+/// # Example
+///
+/// ```
+/// use optim::search::golden_section;
+/// let (x, y) = golden_section(-10.0, 10.0, 1e-9, |x| (x - 1.5) * (x - 1.5) + 2.0);
+/// assert!((x - 1.5).abs() < 1e-6 && (y - 2.0).abs() < 1e-9);
+/// ```
+///
+/// This function has a time complexity of O(log((hi - lo) / eps)) evaluations of `f`.
+pub fn golden_section(mut lo: f64, mut hi: f64, eps: f64, f: impl Fn(f64) -> f64) -> (f64, f64) {
+    assert!(lo <= hi);
+    let r = (5f64.sqrt() - 1.0) / 2.0;
+    let (mut c, mut d) = (hi - r * (hi - lo), lo + r * (hi - lo));
+    let (mut fc, mut fd) = (f(c), f(d));
+    for _ in 0..200 {
+        if hi - lo <= eps {
+            break;
+        }
+        if fc <= fd {
+            hi = d;
+            (d, fd) = (c, fc);
+            c = hi - r * (hi - lo);
+            fc = f(c);
+        } else {
+            lo = c;
+            (c, fc) = (d, fd);
+            d = lo + r * (hi - lo);
+            fd = f(d);
+        }
+    }
+    if fc <= fd { (c, fc) } else { (d, fd) }
+}
+/// Return the minimum `x` in `[lo, hi]` such that `pred(f(x))` and `f(x)`,
+/// where `pred(f(x))` is monotone, that is, false and then true.
+/// `mid(l, r)` returns a point strictly between `l` and `r`,
+/// or `None` if the search should stop.
+/// If `pred(f(hi))` is false, return `None`.
+///
+/// # Example
+///
+/// ```
+/// use optim::search::bisect;
+/// let mid = |l: u64, r: u64| (r - l > 1).then(|| l + (r - l) / 2);
+/// assert_eq!(bisect(0, 100, mid, |x| x * x, |y| *y >= 50), Some((8, 64)));
+/// ```
+///
+/// This function has a time complexity of O(log(hi - lo)) evaluations of `f` for bisection.
+pub fn bisect<X: Copy, T>(
+    lo: X,
+    hi: X,
+    mid: impl Fn(X, X) -> Option<X>,
+    f: impl Fn(X) -> T,
+    pred: impl Fn(&T) -> bool,
+) -> Option<(X, T)> {
+    let mut value = f(hi);
+    if !pred(&value) {
+        return None;
+    }
+    let v = f(lo);
+    if pred(&v) {
+        return Some((lo, v));
+    }
+    let (mut lo, mut hi) = (lo, hi);
+    while let Some(m) = mid(lo, hi) {
+        let v = f(m);
+        if pred(&v) {
+            (hi, value) = (m, v);
+        } else {
+            lo = m;
+        }
+    }
+    Some((hi, value))
+}
+/// Return the minimum integer `x` in `[lo, hi]` such that `pred(f(x))` and `f(x)`,
+/// where `pred(f(x))` is monotone.
+/// If there is no such `x`, return `None`.
+///
+/// # Example
+///
+/// ```
+/// use optim::search::bisect_int;
+/// assert_eq!(bisect_int(-100, 100, |x| x * x * x, |y| *y >= -30), Some((-3, -27)));
+/// ```
+///
+/// This function has a time complexity of O(log(hi - lo)) evaluations of `f`.
+pub fn bisect_int<T>(
+    lo: i64,
+    hi: i64,
+    f: impl Fn(i64) -> T,
+    pred: impl Fn(&T) -> bool,
+) -> Option<(i64, T)> {
+    assert!(lo <= hi);
+    let mid = |l: i64, r: i64| {
+        let d = r as i128 - l as i128;
+        (d > 1).then(|| (l as i128 + d / 2) as i64)
+    };
+    bisect(lo, hi, mid, f, pred)
+}
+/// Return an approximate minimum `x` in `[lo, hi]` such that `pred(f(x))` and `f(x)`,
+/// where `pred(f(x))` is monotone.
+/// The search stops when the interval is shorter than `eps`.
+/// If `pred(f(hi))` is false, return `None`.
+///
+/// # Example
+///
+/// ```
+/// use optim::search::bisect_f64;
+/// let (x, _) = bisect_f64(0.0, 2.0, 1e-12, |x| x * x, |y| *y >= 2.0).unwrap();
+/// assert!((x - 2f64.sqrt()).abs() < 1e-9);
+/// ```
+///
+/// This function has a time complexity of O(log((hi - lo) / eps)) evaluations of `f`.
+pub fn bisect_f64<T>(
+    lo: f64,
+    hi: f64,
+    eps: f64,
+    f: impl Fn(f64) -> T,
+    pred: impl Fn(&T) -> bool,
+) -> Option<(f64, T)> {
+    assert!(lo <= hi);
+    let mid = |l: f64, r: f64| {
+        let m = (l + r) / 2.0;
+        (r - l > eps && l < m && m < r).then_some(m)
+    };
+    bisect(lo, hi, mid, f, pred)
+}
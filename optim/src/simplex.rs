@@ -0,0 +1,259 @@
+use intalgo::rational::Rational;
+use matrix::Matrix;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+/// Tolerance under which a `f64` is regarded as zero.
+pub const EPS: f64 = 1e-9;
+/// Numbers on which the simplex method works.
+pub trait LPNum:
+    Copy
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + From<u8>
+{
+    /// Return whether `self` is regarded as positive.
+    fn is_pos(&self) -> bool;
+    /// Return whether `self` is regarded as negative.
+    fn is_neg(&self) -> bool;
+    /// Return the greatest integer less than or equal to `self`.
+    fn floor(&self) -> Self;
+}
+impl LPNum for f64 {
+    fn is_pos(&self) -> bool {
+        *self > EPS
+    }
+    fn is_neg(&self) -> bool {
+        *self < -EPS
+    }
+    fn floor(&self) -> Self {
+        f64::floor(*self)
+    }
+}
+impl LPNum for Rational {
+    fn is_pos(&self) -> bool {
+        self.num() > 0
+    }
+    fn is_neg(&self) -> bool {
+        self.num() < 0
+    }
+    fn floor(&self) -> Self {
+        Rational::from(Rational::floor(self))
+    }
+}
+/// Result of linear programming.
+#[derive(Clone, Debug, PartialEq)]
+pub enum LPResult<T> {
+    Infeasible,
+    Unbounded,
+    /// `value` is the optimal value, `primal` is an optimal `x`,
+    /// and `dual` is an optimal solution `y` of the dual `min b y s.t. A^T y >= c, y >= 0`.
+    Optimal {
+        value: T,
+        primal: Vec<T>,
+        dual: Vec<T>,
+    },
+}
+/// Solve `max c x s.t. A x <= b, x >= 0` by two-phase simplex method with Bland's rule.
+///
+/// # Example
+///
+/// ```
+/// use matrix::Matrix;
+/// use optim::simplex::{simplex, LPResult};
+/// let a = Matrix::new(2, 2, &[vec![1.0, 1.0], vec![1.0, 3.0]]);
+/// if let LPResult::Optimal { value, primal, .. } = simplex(&a, &[4.0, 6.0], &[1.0, 2.0]) {
+///     assert!((value - 5.0).abs() < 1e-9);
+///     assert!((primal[0] - 3.0).abs() < 1e-9 && (primal[1] - 1.0).abs() < 1e-9);
+/// } else {
+///     unreachable!();
+/// }
+/// ```
+///
+/// This function has a time complexity of O(m n) per pivot.
+pub fn simplex<T: LPNum>(a: &Matrix<T>, b: &[T], c: &[T]) -> LPResult<T> {
+    Tableau::new(a, b, c).solve()
+}
+/// Simplex tableau.
+/// Variables `0..n` are original, `n..n + m` are slacks and `n + m` is artificial.
+pub(crate) struct Tableau<T: LPNum> {
+    m: usize,
+    n: usize,
+    non_basis: Vec<usize>,
+    basis: Vec<usize>,
+    d: Matrix<T>,
+}
+impl<T: LPNum> Tableau<T> {
+    pub(crate) fn new(a: &Matrix<T>, b: &[T], c: &[T]) -> Self {
+        let (m, n) = (b.len(), c.len());
+        assert_eq!(a.h(), m);
+        assert!(m == 0 || a.w() == n);
+        let zero = T::from(0);
+        let d = Matrix::from_fn(m + 2, n + 2, |i, j| {
+            if i < m {
+                if j < n {
+                    a[i][j]
+                } else if j == n {
+                    -T::from(1)
+                } else {
+                    b[i]
+                }
+            } else if i == m {
+                if j < n { -c[j] } else { zero }
+            } else if j == n {
+                T::from(1)
+            } else {
+                zero
+            }
+        });
+        Self {
+            m,
+            n,
+            non_basis: (0..n).chain([n + m]).collect(),
+            basis: (n..n + m).collect(),
+            d,
+        }
+    }
+    pub(crate) fn solve(&mut self) -> LPResult<T> {
+        let (m, n) = (self.m, self.n);
+        let art = n + m;
+        if let Some(r) =
+            (0..m).min_by(|&i, &j| self.d[i][n + 1].partial_cmp(&self.d[j][n + 1]).unwrap())
+            && self.d[r][n + 1].is_neg()
+        {
+            self.pivot(r, n);
+            if !self.run(true) || self.d[m + 1][n + 1].is_neg() {
+                return LPResult::Infeasible;
+            }
+            for i in 0..m {
+                if self.basis[i] == art {
+                    let s = (0..=n)
+                        .filter(|&j| self.d[i][j].is_pos() || self.d[i][j].is_neg())
+                        .max_by(|&j, &k| {
+                            let (x, y) = (abs(self.d[i][j]), abs(self.d[i][k]));
+                            x.partial_cmp(&y).unwrap()
+                        });
+                    if let Some(s) = s {
+                        self.pivot(i, s);
+                    }
+                }
+            }
+        }
+        if !self.run(false) {
+            return LPResult::Unbounded;
+        }
+        let zero = T::from(0);
+        let mut primal = vec![zero; n];
+        for i in 0..m {
+            if self.basis[i] < n {
+                primal[self.basis[i]] = self.d[i][n + 1];
+            }
+        }
+        let mut dual = vec![zero; m];
+        for j in 0..=n {
+            let v = self.non_basis[j];
+            if n <= v && v < n + m {
+                dual[v - n] = self.d[m][j];
+            }
+        }
+        LPResult::Optimal {
+            value: self.d[m][n + 1],
+            primal,
+            dual,
+        }
+    }
+    /// Return Gomory fractional cuts `cut x <= rhs` from the optimal tableau of `A x <= b`.
+    /// Every variable and every element of `A` and `b` must be integral.
+    /// This function has a time complexity of O(m n (m + n)).
+    pub(crate) fn gomory_cuts(&self, a: &Matrix<T>, b: &[T], limit: usize) -> Vec<(Vec<T>, T)> {
+        let (m, n) = (self.m, self.n);
+        let frac = |x: T| x - x.floor();
+        (0..m)
+            .filter(|&i| {
+                let f = frac(self.d[i][n + 1]);
+                f.is_pos() && (T::from(1) - f).is_pos()
+            })
+            .take(limit)
+            .map(|i| {
+                let mut cut = vec![T::from(0); n];
+                let mut rhs = -frac(self.d[i][n + 1]);
+                for j in 0..=n {
+                    let v = self.non_basis[j];
+                    let f = frac(self.d[i][j]);
+                    if v < n {
+                        cut[v] = cut[v] - f;
+                    } else if v < n + m {
+                        for (c, a) in cut.iter_mut().zip(a[v - n].iter()) {
+                            *c = *c + f * *a;
+                        }
+                        rhs = rhs + f * b[v - n];
+                    }
+                }
+                (cut, rhs)
+            })
+            .collect()
+    }
+    /// Run pivots until optimal by Bland's rule.
+    /// If the objective is unbounded, return false.
+    fn run(&mut self, is_auxiliary: bool) -> bool {
+        let (m, n) = (self.m, self.n);
+        let x = if is_auxiliary { m + 1 } else { m };
+        loop {
+            let s = (0..=n)
+                .filter(|&j| is_auxiliary || self.non_basis[j] != n + m)
+                .filter(|&j| self.d[x][j].is_neg())
+                .min_by_key(|&j| self.non_basis[j]);
+            let Some(s) = s else {
+                return true;
+            };
+            let mut r: Option<usize> = None;
+            for i in (0..m).filter(|&i| self.d[i][s].is_pos()) {
+                r = Some(match r {
+                    None => i,
+                    Some(k) => {
+                        let diff =
+                            self.d[i][n + 1] / self.d[i][s] - self.d[k][n + 1] / self.d[k][s];
+                        if diff.is_neg() || (!diff.is_pos() && self.basis[i] < self.basis[k]) {
+                            i
+                        } else {
+                            k
+                        }
+                    }
+                });
+            }
+            let Some(r) = r else {
+                return false;
+            };
+            self.pivot(r, s);
+        }
+    }
+    /// Exchange `basis[r]` and `non_basis[s]`.
+    /// This function has a time complexity of O(m n).
+    fn pivot(&mut self, r: usize, s: usize) {
+        let (m, w) = (self.m, self.n + 2);
+        let inv = T::from(1) / self.d[r][s];
+        let a = self.d[r].to_vec();
+        for i in (0..m + 2).filter(|&i| i != r) {
+            let row = &mut self.d[i];
+            if row[s].is_pos() || row[s].is_neg() {
+                let f = row[s] * inv;
+                for j in 0..w {
+                    row[j] = row[j] - a[j] * f;
+                }
+                row[s] = -(a[s] * f) * inv;
+            } else {
+                row[s] = -(row[s] * inv);
+            }
+        }
+        for j in (0..w).filter(|&j| j != s) {
+            self.d[r][j] = self.d[r][j] * inv;
+        }
+        self.d[r][s] = inv;
+        std::mem::swap(&mut self.basis[r], &mut self.non_basis[s]);
+    }
+}
+fn abs<T: LPNum>(x: T) -> T {
+    if x.is_neg() { -x } else { x }
+}
@@ -0,0 +1,267 @@
+use algebra::range_to_pair;
+use std::ops::RangeBounds;
+/// Segment Tree Beats over `i64` supporting range chmin, chmax, add and assign
+/// with range sum, max and min.
+/// Sums must fit in `i64`.
+///
+/// # Example
+///
+/// ```
+/// use segtree::beats::SegTreeBeats;
+/// let mut st = [1, 5, 3, 8, 2].into_iter().collect::<SegTreeBeats>();
+/// st.range_chmin(.., 4);
+/// assert_eq!(st.range_sum(..), 14);
+/// st.range_chmax(1..3, 4);
+/// st.range_add(3.., 10);
+/// assert_eq!(st.range_max(..), 14);
+/// assert_eq!(st.range_min(..), 1);
+/// st.range_assign(..2, 0);
+/// assert_eq!(st.range_sum(..), 30);
+/// ```
+///
+pub struct SegTreeBeats {
+    n: usize,
+    nodes: Vec<Node>,
+}
+#[derive(Clone, Copy)]
+struct Node {
+    len: i64,
+    sum: i64,
+    /// The maximum, the strict second maximum and the count of the maximum.
+    max: (i64, i64, i64),
+    /// The minimum, the strict second minimum and the count of the minimum.
+    min: (i64, i64, i64),
+    add: i64,
+}
+impl Node {
+    fn leaf(x: i64) -> Self {
+        Self {
+            len: 1,
+            sum: x,
+            max: (x, i64::MIN, 1),
+            min: (x, i64::MAX, 1),
+            add: 0,
+        }
+    }
+    fn merge(l: &Self, r: &Self) -> Self {
+        let max = if l.max.0 > r.max.0 {
+            (l.max.0, l.max.1.max(r.max.0), l.max.2)
+        } else if l.max.0 < r.max.0 {
+            (r.max.0, l.max.0.max(r.max.1), r.max.2)
+        } else {
+            (l.max.0, l.max.1.max(r.max.1), l.max.2 + r.max.2)
+        };
+        let min = if l.min.0 < r.min.0 {
+            (l.min.0, l.min.1.min(r.min.0), l.min.2)
+        } else if l.min.0 > r.min.0 {
+            (r.min.0, l.min.0.min(r.min.1), r.min.2)
+        } else {
+            (l.min.0, l.min.1.min(r.min.1), l.min.2 + r.min.2)
+        };
+        Self {
+            len: l.len + r.len,
+            sum: l.sum + r.sum,
+            max,
+            min,
+            add: 0,
+        }
+    }
+    /// Apply chmin by `x`, where `x` is greater than the second maximum.
+    fn chmin(&mut self, x: i64) {
+        if self.max.0 <= x {
+            return;
+        }
+        self.sum += (x - self.max.0) * self.max.2;
+        if self.max.0 == self.min.0 {
+            self.min.0 = x;
+        } else if self.max.0 == self.min.1 {
+            self.min.1 = x;
+        }
+        self.max.0 = x;
+    }
+    /// Apply chmax by `x`, where `x` is less than the second minimum.
+    fn chmax(&mut self, x: i64) {
+        if self.min.0 >= x {
+            return;
+        }
+        self.sum += (x - self.min.0) * self.min.2;
+        if self.min.0 == self.max.0 {
+            self.max.0 = x;
+        } else if self.min.0 == self.max.1 {
+            self.max.1 = x;
+        }
+        self.min.0 = x;
+    }
+    fn add(&mut self, x: i64) {
+        self.sum += x * self.len;
+        self.max.0 += x;
+        if self.max.1 != i64::MIN {
+            self.max.1 += x;
+        }
+        self.min.0 += x;
+        if self.min.1 != i64::MAX {
+            self.min.1 += x;
+        }
+        self.add += x;
+    }
+}
+/// Kind of range updates.
+#[derive(Clone, Copy)]
+enum Query {
+    Chmin(i64),
+    Chmax(i64),
+    Add(i64),
+}
+impl SegTreeBeats {
+    /// This is initializer of `SegTreeBeats` with all elements 0.
+    /// This function has a time complexity of O(n).
+    pub fn new(n: usize) -> Self {
+        std::iter::repeat_n(0, n).collect()
+    }
+    /// Update `a_i` to `min(a_i, x)` for `i` in `range`.
+    /// This function has a time complexity of amortized O(log^2 n).
+    pub fn range_chmin(&mut self, range: impl RangeBounds<usize>, x: i64) {
+        self.update(range, Query::Chmin(x));
+    }
+    /// Update `a_i` to `max(a_i, x)` for `i` in `range`.
+    /// This function has a time complexity of amortized O(log^2 n).
+    pub fn range_chmax(&mut self, range: impl RangeBounds<usize>, x: i64) {
+        self.update(range, Query::Chmax(x));
+    }
+    /// Update `a_i` to `a_i + x` for `i` in `range`.
+    /// This function has a time complexity of amortized O(log^2 n).
+    pub fn range_add(&mut self, range: impl RangeBounds<usize>, x: i64) {
+        self.update(range, Query::Add(x));
+    }
+    /// Update `a_i` to `x` for `i` in `range`.
+    /// This function has a time complexity of amortized O(log^2 n).
+    pub fn range_assign(&mut self, range: impl RangeBounds<usize>, x: i64) {
+        let (l, r) = range_to_pair(range, self.n);
+        self.update(l..r, Query::Chmin(x));
+        self.update(l..r, Query::Chmax(x));
+    }
+    /// Return `a_l + ... + a_r`.
+    /// This function has a time complexity of O(log n).
+    pub fn range_sum(&mut self, range: impl RangeBounds<usize>) -> i64 {
+        self.fold(range, 0, &|node| node.sum, &|a, b| a + b)
+    }
+    /// Return `max(a_l, ..., a_r)`, which is `i64::MIN` for an empty range.
+    /// This function has a time complexity of O(log n).
+    pub fn range_max(&mut self, range: impl RangeBounds<usize>) -> i64 {
+        self.fold(range, i64::MIN, &|node| node.max.0, &|a, b| a.max(b))
+    }
+    /// Return `min(a_l, ..., a_r)`, which is `i64::MAX` for an empty range.
+    /// This function has a time complexity of O(log n).
+    pub fn range_min(&mut self, range: impl RangeBounds<usize>) -> i64 {
+        self.fold(range, i64::MAX, &|node| node.min.0, &|a, b| a.min(b))
+    }
+    fn update(&mut self, range: impl RangeBounds<usize>, q: Query) {
+        let (l, r) = range_to_pair(range, self.n);
+        assert!(l <= r && r <= self.n);
+        if l < r {
+            self.update_rec(1, 0, self.n, l, r, q);
+        }
+    }
+    fn update_rec(&mut self, k: usize, l: usize, r: usize, ql: usize, qr: usize, q: Query) {
+        let node = &mut self.nodes[k];
+        if qr <= l || r <= ql {
+            return;
+        }
+        match q {
+            Query::Chmin(x) if node.max.0 <= x => return,
+            Query::Chmax(x) if node.min.0 >= x => return,
+            _ => {}
+        }
+        if ql <= l && r <= qr {
+            match q {
+                Query::Chmin(x) if node.max.1 < x => return node.chmin(x),
+                Query::Chmax(x) if node.min.1 > x => return node.chmax(x),
+                Query::Add(x) => return node.add(x),
+                _ => {}
+            }
+        }
+        self.push(k);
+        let m = (l + r) / 2;
+        self.update_rec(2 * k, l, m, ql, qr, q);
+        self.update_rec(2 * k + 1, m, r, ql, qr, q);
+        self.nodes[k] = Node::merge(&self.nodes[2 * k], &self.nodes[2 * k + 1]);
+    }
+    fn fold(
+        &mut self,
+        range: impl RangeBounds<usize>,
+        e: i64,
+        get: &impl Fn(&Node) -> i64,
+        op: &impl Fn(i64, i64) -> i64,
+    ) -> i64 {
+        let (l, r) = range_to_pair(range, self.n);
+        assert!(l <= r && r <= self.n);
+        if l == r {
+            return e;
+        }
+        self.fold_rec(1, 0, self.n, l, r, e, get, op)
+    }
+    #[allow(clippy::too_many_arguments)]
+    fn fold_rec(
+        &mut self,
+        k: usize,
+        l: usize,
+        r: usize,
+        ql: usize,
+        qr: usize,
+        e: i64,
+        get: &impl Fn(&Node) -> i64,
+        op: &impl Fn(i64, i64) -> i64,
+    ) -> i64 {
+        if qr <= l || r <= ql {
+            return e;
+        }
+        if ql <= l && r <= qr {
+            return get(&self.nodes[k]);
+        }
+        self.push(k);
+        let m = (l + r) / 2;
+        let a = self.fold_rec(2 * k, l, m, ql, qr, e, get, op);
+        let b = self.fold_rec(2 * k + 1, m, r, ql, qr, e, get, op);
+        op(a, b)
+    }
+    /// Push updates of node `k` to its children.
+    /// This function has a time complexity of O(1).
+    fn push(&mut self, k: usize) {
+        let node = self.nodes[k];
+        for c in [2 * k, 2 * k + 1] {
+            let child = &mut self.nodes[c];
+            if node.add != 0 {
+                child.add(node.add);
+            }
+            child.chmin(node.max.0);
+            child.chmax(node.min.0);
+        }
+        self.nodes[k].add = 0;
+    }
+    fn build(&mut self, k: usize, l: usize, r: usize, a: &[i64]) {
+        if r - l == 1 {
+            self.nodes[k] = Node::leaf(a[l]);
+            return;
+        }
+        let m = (l + r) / 2;
+        self.build(2 * k, l, m, a);
+        self.build(2 * k + 1, m, r, a);
+        self.nodes[k] = Node::merge(&self.nodes[2 * k], &self.nodes[2 * k + 1]);
+    }
+}
+/// Constructor from iter to `SegTreeBeats`.
+/// This function has a time complexity of O(n).
+impl FromIterator<i64> for SegTreeBeats {
+    fn from_iter<I: IntoIterator<Item = i64>>(iter: I) -> Self {
+        let a = iter.into_iter().collect::<Vec<_>>();
+        let n = a.len();
+        let mut res = Self {
+            n,
+            nodes: vec![Node::leaf(0); 4 * n.max(1)],
+        };
+        if n > 0 {
+            res.build(1, 0, n, &a);
+        }
+        res
+    }
+}
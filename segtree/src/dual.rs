@@ -52,17 +52,8 @@ impl<T: MonoidAction> DualSegTree<T> {
     /// This function has a time complexity of O(log n).
     pub fn range_apply(&mut self, range: impl RangeBounds<usize>, f: &T::S) {
         use std::mem::replace;
-        use std::ops::Bound::{Excluded, Included, Unbounded};
-        let mut l = match range.start_bound() {
-            Unbounded => 0,
-            Included(x) => *x,
-            Excluded(x) => x + 1,
-        } + self.m;
-        let mut r = match range.end_bound() {
-            Unbounded => self.n,
-            Included(x) => x + 1,
-            Excluded(x) => *x,
-        } + self.m;
+        let (l, r) = algebra::range_to_pair(range, self.n);
+        let (mut l, mut r) = (l + self.m, r + self.m);
         // If monoid action is commutative, this propagation is not needed.
         (1..=self.k).rev().for_each(|i| {
             if (l >> i) << i != l {
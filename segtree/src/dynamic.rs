@@ -0,0 +1,156 @@
+use algebra::Monoid;
+use std::ops::RangeBounds;
+/// Dynamic Segment Tree over indices in `[lo, hi)`, whose nodes are created on demand.
+///
+/// # Example
+///
+/// ```
+/// use algebra::Monoid;
+/// use segtree::dynamic::DynamicSegTree;
+/// struct Sum;
+/// impl Monoid for Sum {
+///     type S = i64;
+///     const E: i64 = 0;
+///     fn op(lhs: &i64, rhs: &i64) -> i64 {
+///         lhs + rhs
+///     }
+/// }
+/// let mut st = DynamicSegTree::<Sum>::new(-1_000_000_000_000_000_000, 1_000_000_000_000_000_000);
+/// st.set_at(-5, &3);
+/// st.set_at(999_999_999_999_999_999, &4);
+/// assert_eq!(st.range_fold(..), 7);
+/// assert_eq!(st.range_fold(-5..0), 3);
+/// assert_eq!(st.get_at(0), 0);
+/// ```
+///
+pub struct DynamicSegTree<T: Monoid> {
+    lo: i64,
+    hi: i64,
+    root: usize,
+    /// `nodes[0]` is the empty node.
+    nodes: Vec<Node<T::S>>,
+}
+pub(crate) struct Node<S> {
+    pub(crate) val: S,
+    pub(crate) child: [usize; 2],
+}
+impl<T: Monoid> DynamicSegTree<T> {
+    /// This is initializer of `DynamicSegTree` with all elements `E`.
+    /// This function has a time complexity of O(1).
+    pub fn new(lo: i64, hi: i64) -> Self {
+        assert!(lo < hi);
+        Self {
+            lo,
+            hi,
+            root: 0,
+            nodes: vec![Node {
+                val: T::E,
+                child: [0, 0],
+            }],
+        }
+    }
+    /// Update `i` th, from `a_i` to `x`.
+    /// This function has a time complexity of O(log(hi - lo)).
+    pub fn set_at(&mut self, i: i64, x: &T::S) {
+        assert!(self.lo <= i && i < self.hi);
+        let (mut l, mut r) = (self.lo as i128, self.hi as i128);
+        if self.root == 0 {
+            self.root = self.alloc();
+        }
+        let mut path = vec![self.root];
+        while r - l > 1 {
+            let m = l + (r - l) / 2;
+            let d = (i as i128 >= m) as usize;
+            if d == 0 {
+                r = m
+            } else {
+                l = m
+            }
+            let k = *path.last().unwrap();
+            if self.nodes[k].child[d] == 0 {
+                self.nodes[k].child[d] = self.alloc();
+            }
+            path.push(self.nodes[k].child[d]);
+        }
+        self.nodes[path.pop().unwrap()].val = x.clone();
+        for k in path.into_iter().rev() {
+            let [a, b] = self.nodes[k].child;
+            self.nodes[k].val = T::op(&self.nodes[a].val, &self.nodes[b].val);
+        }
+    }
+    /// Return `a_i`.
+    /// This function has a time complexity of O(log(hi - lo)).
+    pub fn get_at(&self, i: i64) -> T::S {
+        assert!(self.lo <= i && i < self.hi);
+        let (mut l, mut r) = (self.lo as i128, self.hi as i128);
+        let mut k = self.root;
+        while k != 0 && r - l > 1 {
+            let m = l + (r - l) / 2;
+            let d = (i as i128 >= m) as usize;
+            if d == 0 {
+                r = m
+            } else {
+                l = m
+            }
+            k = self.nodes[k].child[d];
+        }
+        self.nodes[k].val.clone()
+    }
+    /// Return `op(a_l, ..., a_r)`.
+    /// This function has a time complexity of O(log(hi - lo)).
+    pub fn range_fold(&self, range: impl RangeBounds<i64>) -> T::S {
+        let (l, r) = bounds(range, self.lo, self.hi);
+        fold::<T>(
+            &self.nodes,
+            self.root,
+            self.lo as i128,
+            self.hi as i128,
+            l,
+            r,
+        )
+    }
+    fn alloc(&mut self) -> usize {
+        self.nodes.push(Node {
+            val: T::E,
+            child: [0, 0],
+        });
+        self.nodes.len() - 1
+    }
+}
+/// Return `range` clipped to `[lo, hi)` as a half-open interval in i128.
+pub(crate) fn bounds(range: impl RangeBounds<i64>, lo: i64, hi: i64) -> (i128, i128) {
+    use std::ops::Bound::{Excluded, Included, Unbounded};
+    let l = match range.start_bound() {
+        Unbounded => lo as i128,
+        Included(x) => *x as i128,
+        Excluded(x) => *x as i128 + 1,
+    };
+    let r = match range.end_bound() {
+        Unbounded => hi as i128,
+        Included(x) => *x as i128 + 1,
+        Excluded(x) => *x as i128,
+    };
+    (l.max(lo as i128), r.min(hi as i128))
+}
+/// Return the fold of `[ql, qr)` in the subtree of node `k` covering `[l, r)`.
+pub(crate) fn fold<T: Monoid>(
+    nodes: &[Node<T::S>],
+    k: usize,
+    l: i128,
+    r: i128,
+    ql: i128,
+    qr: i128,
+) -> T::S {
+    if k == 0 || qr <= l || r <= ql {
+        return T::E;
+    }
+    if ql <= l && r <= qr {
+        return nodes[k].val.clone();
+    }
+    let m = l + (r - l) / 2;
+    let [a, b] = nodes[k].child;
+    T::op(
+        &fold::<T>(nodes, a, l, m, ql, qr),
+        &fold::<T>(nodes, b, m, r, ql, qr),
+    )
+}
@@ -0,0 +1,76 @@
+use super::lazy::Action;
+use algebra::Monoid;
+use algebra::laws::Rng;
+/// Counterexample of a law of an action `A` of `F` on `V`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ActionViolation<S, F> {
+    /// `apply(x, E) != x`.
+    MapIdentity(S),
+    /// `apply(E, f) != E`.
+    ValueIdentity(F),
+    /// `apply(op(x, y), f) != op(apply(x, f), apply(y, f))`.
+    Homomorphism(S, S, F),
+    /// `apply(apply(x, f), g) != apply(x, op(f, g))`.
+    Composition(S, F, F),
+}
+/// Check the laws of an action `A` used by `LazySegTree` on `trials` samples
+/// by `sample_val` and `sample_map`.
+/// Return the first counterexample found.
+///
+/// # Example
+///
+/// ```
+/// use algebra::{Additive, Min, Monoid};
+/// use segtree::laws::{ActionViolation, check_action};
+/// use segtree::lazy::{Action, AddMin};
+/// struct MulMin;
+/// impl Action<Min<i64>, Additive<i64>> for MulMin {
+///     fn apply(val: &mut i64, func: &i64) {
+///         if *val != i64::MAX {
+///             *val *= func;
+///         }
+///     }
+/// }
+/// let val = |rng: &mut algebra::laws::Rng| rng.range(100) as i64 - 50;
+/// let map = |rng: &mut algebra::laws::Rng| rng.range(10) as i64 - 5;
+/// assert_eq!(check_action::<Min<i64>, Additive<i64>, AddMin<i64>>(1, 100, val, map), Ok(()));
+/// let res = check_action::<Min<i64>, Additive<i64>, MulMin>(1, 100, val, map);
+/// assert!(matches!(res, Err(ActionViolation::MapIdentity(_))));
+/// ```
+///
+/// This function has a time complexity of O(trials).
+pub fn check_action<V: Monoid, F: Monoid, A: Action<V, F>>(
+    seed: u64,
+    trials: usize,
+    mut sample_val: impl FnMut(&mut Rng) -> V::S,
+    mut sample_map: impl FnMut(&mut Rng) -> F::S,
+) -> Result<(), ActionViolation<V::S, F::S>>
+where
+    V::S: PartialEq,
+{
+    let apply = |x: &V::S, f: &F::S| {
+        let mut x = x.clone();
+        A::apply(&mut x, f);
+        x
+    };
+    let mut rng = Rng::new(seed);
+    for _ in 0..trials {
+        let x = sample_val(&mut rng);
+        let y = sample_val(&mut rng);
+        let f = sample_map(&mut rng);
+        let g = sample_map(&mut rng);
+        if apply(&x, &F::E) != x {
+            return Err(ActionViolation::MapIdentity(x));
+        }
+        if apply(&V::E, &f) != V::E {
+            return Err(ActionViolation::ValueIdentity(f));
+        }
+        if apply(&V::op(&x, &y), &f) != V::op(&apply(&x, &f), &apply(&y, &f)) {
+            return Err(ActionViolation::Homomorphism(x, y, f));
+        }
+        if apply(&apply(&x, &f), &g) != apply(&x, &F::op(&f, &g)) {
+            return Err(ActionViolation::Composition(x, f, g));
+        }
+    }
+    Ok(())
+}
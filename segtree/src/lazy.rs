@@ -1,33 +1,68 @@
-use algebra::Monoid;
+use super::MonoidOps;
+use algebra::{Additive, Affine, Bounded, Max, Min, Monoid, Semiring, range_to_pair};
+use std::ops::RangeBounds;
 pub trait Action<V: Monoid, F: Monoid> {
     fn apply(val: &mut <V as Monoid>::S, func: &<F as Monoid>::S);
 }
-/// Lazy Segment Tree.
-pub struct LazySegTree<V: Monoid, F: Monoid, A: Action<V, F>> {
+/// `MonoidOps` of values and maps of an action on them,
+/// which are given by an `Action` or at runtime.
+pub(crate) trait ActionOps<S, F>: MonoidOps<S> {
+    fn id(&self) -> F;
+    fn composition(&self, f: &F, g: &F) -> F;
+    fn mapping(&self, val: &mut S, f: &F);
+}
+/// `ActionOps` of an `Action` `A` of `F` on `V`.
+pub(crate) struct StaticAction<V, F, A>(std::marker::PhantomData<(V, F, A)>);
+impl<V: Monoid, F: Monoid, A: Action<V, F>> MonoidOps<V::S> for StaticAction<V, F, A> {
+    fn e(&self) -> V::S {
+        V::E
+    }
+    fn op(&self, lhs: &V::S, rhs: &V::S) -> V::S {
+        V::op(lhs, rhs)
+    }
+}
+impl<V: Monoid, F: Monoid, A: Action<V, F>> ActionOps<V::S, F::S> for StaticAction<V, F, A> {
+    fn id(&self) -> F::S {
+        F::E
+    }
+    fn composition(&self, f: &F::S, g: &F::S) -> F::S {
+        F::op(f, g)
+    }
+    fn mapping(&self, val: &mut V::S, f: &F::S) {
+        A::apply(val, f);
+    }
+}
+/// Lazy Segment Tree over `ActionOps`, which is shared by `LazySegTree` and `RuntimeLazySegTree`.
+pub(crate) struct LazyCore<S, F, O> {
     n: usize,
     m: usize,
     k: u32,
-    vals: Vec<<V as Monoid>::S>,
-    maps: Vec<<F as Monoid>::S>,
-    _marker: std::marker::PhantomData<A>,
+    ops: O,
+    vals: Vec<S>,
+    maps: Vec<F>,
 }
-impl<V: Monoid, F: Monoid, A: Action<V, F>> LazySegTree<V, F, A> {
-    /// This is initilizer of `LazySegTree`.
-    /// This function has a time complexity of O(n).
-    pub fn new(n: usize) -> Self {
+impl<S: Clone, F: Clone, O: ActionOps<S, F>> LazyCore<S, F, O> {
+    pub(crate) fn new(n: usize, ops: O) -> Self {
         let m = n.next_power_of_two();
         Self {
             n,
             m,
             k: m.trailing_zeros(),
-            vals: vec![<V as Monoid>::E; 2 * m],
-            maps: vec![<F as Monoid>::E; 2 * m],
-            _marker: std::marker::PhantomData,
+            vals: vec![ops.e(); 2 * m],
+            maps: vec![ops.id(); 2 * m],
+            ops,
         }
     }
-    /// Return the value of index `i`.
-    /// This function has a time complexity of O(log n).
-    pub fn get(&mut self, i: usize) -> &<V as Monoid>::S {
+    pub(crate) fn build(a: Vec<S>, ops: O) -> Self {
+        let mut res = Self::new(a.len(), ops);
+        let m = res.m;
+        res.vals[m..m + a.len()].clone_from_slice(&a);
+        for i in (1..m).rev() {
+            res.update_node(i);
+        }
+        res
+    }
+    pub(crate) fn get(&mut self, i: usize) -> &S {
         assert!(i < self.n);
         let i = i + self.m;
         for v in (1..=self.k).rev() {
@@ -35,9 +70,7 @@ impl<V: Monoid, F: Monoid, A: Action<V, F>> LazySegTree<V, F, A> {
         }
         &self.vals[i]
     }
-    /// Update the value of index `i` to `x`.
-    /// This function has a time complexity of O(log n).
-    pub fn update(&mut self, i: usize, x: &<V as Monoid>::S) {
+    pub(crate) fn update(&mut self, i: usize, x: &S) {
         assert!(i < self.n);
         let i = i + self.m;
         for v in (1..=self.k).rev() {
@@ -45,88 +78,51 @@ impl<V: Monoid, F: Monoid, A: Action<V, F>> LazySegTree<V, F, A> {
         }
         self.vals[i] = x.clone();
         for v in 1..=self.k {
-            self.vals[i >> v] =
-                <V as Monoid>::op(&self.vals[2 * (i >> v)], &self.vals[2 * (i >> v) + 1])
+            self.update_node(i >> v);
         }
     }
-    /// Return the fold by op in `range`.
-    /// This function has a time complexity of O(log n).
-    pub fn range_fold(&mut self, range: impl std::ops::RangeBounds<usize>) -> <V as Monoid>::S {
-        use std::ops::Bound::{Excluded, Included, Unbounded};
-        let mut l = match range.start_bound() {
-            Unbounded => 0,
-            Included(l) => *l,
-            Excluded(l) => l + 1,
-        } + self.m;
-        let mut r = match range.end_bound() {
-            Unbounded => self.n,
-            Included(r) => r + 1,
-            Excluded(r) => *r,
-        } + self.m;
-        assert!(l <= r);
-        assert!(l < self.n + self.m);
-        assert!(r <= self.n + self.m);
-        for v in (1..=self.k).rev() {
-            if (l >> v) << v != l {
-                self.push(l >> v);
-            }
-            if (r >> v) << v != r {
-                self.push((r - 1) >> v);
-            }
+    pub(crate) fn range_fold(&mut self, range: impl RangeBounds<usize>) -> S {
+        let (l, r) = range_to_pair(range, self.n);
+        assert!(l <= r && r <= self.n);
+        if l == r {
+            return self.ops.e();
         }
-        let mut left = <V as Monoid>::E;
-        let mut right = <V as Monoid>::E;
+        let (mut l, mut r) = (l + self.m, r + self.m);
+        self.push_boundary(l, r);
+        let mut left = self.ops.e();
+        let mut right = self.ops.e();
         while l < r {
             if l & 1 == 1 {
-                left = <V as Monoid>::op(&left, &self.vals[l]);
+                left = self.ops.op(&left, &self.vals[l]);
                 l += 1;
             }
             if r & 1 == 1 {
                 r -= 1;
-                right = <V as Monoid>::op(&self.vals[r], &right);
+                right = self.ops.op(&self.vals[r], &right);
             }
             l >>= 1;
             r >>= 1;
         }
-        <V as Monoid>::op(&left, &right)
+        self.ops.op(&left, &right)
     }
-    /// Apply `f` to the value which index is in `range`.
-    /// This function has a time complexity of O(log n).
-    pub fn range_apply(&mut self, range: impl std::ops::RangeBounds<usize>, f: &<F as Monoid>::S) {
-        use std::ops::Bound::{Excluded, Included, Unbounded};
-        let l = match range.start_bound() {
-            Unbounded => 0,
-            Included(l) => *l,
-            Excluded(l) => l + 1,
-        } + self.m;
-        let r = match range.end_bound() {
-            Unbounded => self.n,
-            Included(r) => r + 1,
-            Excluded(r) => *r,
-        } + self.m;
-        assert!(l <= r);
-        assert!(l < self.n + self.m);
-        assert!(r <= self.n + self.m);
-        for v in (1..=self.k).rev() {
-            if (l >> v) << v != l {
-                self.push(l >> v);
-            }
-            if (r >> v) << v != r {
-                self.push((r - 1) >> v);
-            }
+    pub(crate) fn range_apply(&mut self, range: impl RangeBounds<usize>, f: &F) {
+        let (l, r) = range_to_pair(range, self.n);
+        assert!(l <= r && r <= self.n);
+        if l == r {
+            return;
         }
+        let (l, r) = (l + self.m, r + self.m);
+        self.push_boundary(l, r);
         {
             let (mut l, mut r) = (l, r);
             while l < r {
                 if l & 1 == 1 {
-                    A::apply(&mut self.vals[l], f);
-                    self.maps[l] = <F as Monoid>::op(&self.maps[l], f);
+                    self.apply_node(l, f);
                     l += 1;
                 }
                 if r & 1 == 1 {
                     r -= 1;
-                    A::apply(&mut self.vals[r], f);
-                    self.maps[r] = <F as Monoid>::op(&self.maps[r], f);
+                    self.apply_node(r, f);
                 }
                 l >>= 1;
                 r >>= 1;
@@ -134,14 +130,90 @@ impl<V: Monoid, F: Monoid, A: Action<V, F>> LazySegTree<V, F, A> {
         }
         for v in 1..=self.k {
             if (l >> v) << v != l {
-                self.vals[l >> v] =
-                    <V as Monoid>::op(&self.vals[2 * (l >> v)], &self.vals[2 * (l >> v) + 1]);
+                self.update_node(l >> v);
+            }
+            if (r >> v) << v != r {
+                self.update_node((r - 1) >> v);
+            }
+        }
+    }
+    pub(crate) fn max_right(&mut self, l: usize, pred: impl Fn(&S) -> bool) -> usize {
+        assert!(l <= self.n);
+        assert!(pred(&self.ops.e()));
+        if l == self.n {
+            return self.n;
+        }
+        let mut l = l + self.m;
+        for v in (1..=self.k).rev() {
+            self.push(l >> v);
+        }
+        let mut sum = self.ops.e();
+        loop {
+            l >>= l.trailing_zeros();
+            let s = self.ops.op(&sum, &self.vals[l]);
+            if !pred(&s) {
+                while l < self.m {
+                    self.push(l);
+                    l *= 2;
+                    let s = self.ops.op(&sum, &self.vals[l]);
+                    if pred(&s) {
+                        sum = s;
+                        l += 1;
+                    }
+                }
+                return l - self.m;
+            }
+            sum = s;
+            l += 1;
+            if l.is_power_of_two() {
+                return self.n;
+            }
+        }
+    }
+    pub(crate) fn min_left(&mut self, r: usize, pred: impl Fn(&S) -> bool) -> usize {
+        assert!(r <= self.n);
+        assert!(pred(&self.ops.e()));
+        if r == 0 {
+            return 0;
+        }
+        let mut r = r + self.m;
+        for v in (1..=self.k).rev() {
+            self.push((r - 1) >> v);
+        }
+        let mut sum = self.ops.e();
+        loop {
+            r -= 1;
+            while r > 1 && r & 1 == 1 {
+                r >>= 1;
+            }
+            let s = self.ops.op(&self.vals[r], &sum);
+            if !pred(&s) {
+                while r < self.m {
+                    self.push(r);
+                    r = 2 * r + 1;
+                    let s = self.ops.op(&self.vals[r], &sum);
+                    if pred(&s) {
+                        sum = s;
+                        r -= 1;
+                    }
+                }
+                return r + 1 - self.m;
+            }
+            sum = s;
+            if r.is_power_of_two() {
+                return 0;
+            }
+        }
+    }
+    /// Push actions on the paths to the leaves `l` and `r - 1`.
+    /// This function has a time complexity of O(log n).
+    fn push_boundary(&mut self, l: usize, r: usize) {
+        for v in (1..=self.k).rev() {
+            if (l >> v) << v != l {
+                self.push(l >> v);
             }
             if (r >> v) << v != r {
-                self.vals[(r - 1) >> v] = <V as Monoid>::op(
-                    &self.vals[2 * ((r - 1) >> v)],
-                    &self.vals[2 * ((r - 1) >> v) + 1],
-                );
+                self.push((r - 1) >> v);
             }
         }
     }
@@ -149,12 +221,61 @@ impl<V: Monoid, F: Monoid, A: Action<V, F>> LazySegTree<V, F, A> {
     /// Apply its action and push to its children.
     /// This function has a time complexity of O(1).
     fn push(&mut self, k: usize) {
-        use std::mem::replace;
-        let f = replace(&mut self.maps[k], <F as Monoid>::E);
-        A::apply(&mut self.vals[2 * k], &f);
-        A::apply(&mut self.vals[2 * k + 1], &f);
-        self.maps[2 * k] = <F as Monoid>::op(&self.maps[2 * k], &f);
-        self.maps[2 * k + 1] = <F as Monoid>::op(&self.maps[2 * k + 1], &f);
+        let f = std::mem::replace(&mut self.maps[k], self.ops.id());
+        self.apply_node(2 * k, &f);
+        self.apply_node(2 * k + 1, &f);
+    }
+    fn apply_node(&mut self, k: usize, f: &F) {
+        self.ops.mapping(&mut self.vals[k], f);
+        self.maps[k] = self.ops.composition(&self.maps[k], f);
+    }
+    fn update_node(&mut self, k: usize) {
+        self.vals[k] = self.ops.op(&self.vals[2 * k], &self.vals[2 * k + 1]);
+    }
+}
+/// Lazy Segment Tree.
+pub struct LazySegTree<V: Monoid, F: Monoid, A: Action<V, F>> {
+    core: LazyCore<<V as Monoid>::S, <F as Monoid>::S, StaticAction<V, F, A>>,
+}
+impl<V: Monoid, F: Monoid, A: Action<V, F>> LazySegTree<V, F, A> {
+    /// This is initilizer of `LazySegTree`.
+    /// This function has a time complexity of O(n).
+    pub fn new(n: usize) -> Self {
+        Self {
+            core: LazyCore::new(n, StaticAction(std::marker::PhantomData)),
+        }
+    }
+    /// Return the value of index `i`.
+    /// This function has a time complexity of O(log n).
+    pub fn get(&mut self, i: usize) -> &<V as Monoid>::S {
+        self.core.get(i)
+    }
+    /// Update the value of index `i` to `x`.
+    /// This function has a time complexity of O(log n).
+    pub fn update(&mut self, i: usize, x: &<V as Monoid>::S) {
+        self.core.update(i, x);
+    }
+    /// Return the fold by op in `range`.
+    /// This function has a time complexity of O(log n).
+    pub fn range_fold(&mut self, range: impl RangeBounds<usize>) -> <V as Monoid>::S {
+        self.core.range_fold(range)
+    }
+    /// Apply `f` to the value which index is in `range`.
+    /// This function has a time complexity of O(log n).
+    pub fn range_apply(&mut self, range: impl RangeBounds<usize>, f: &<F as Monoid>::S) {
+        self.core.range_apply(range, f);
+    }
+    /// Return the maximum `r` such that `pred(op(a_l, ..., a_{r - 1}))`,
+    /// where `pred` is monotone and `pred(E)` is true.
+    /// This function has a time complexity of O(log n).
+    pub fn max_right(&mut self, l: usize, pred: impl Fn(&<V as Monoid>::S) -> bool) -> usize {
+        self.core.max_right(l, pred)
+    }
+    /// Return the minimum `l` such that `pred(op(a_l, ..., a_{r - 1}))`,
+    /// where `pred` is monotone and `pred(E)` is true.
+    /// This function has a time complexity of O(log n).
+    pub fn min_left(&mut self, r: usize, pred: impl Fn(&<V as Monoid>::S) -> bool) -> usize {
+        self.core.min_left(r, pred)
     }
 }
 /// Construct from iter to `lazysegtree`
@@ -165,20 +286,101 @@ impl<V: Monoid, F: Monoid, A: Action<V, F>> std::iter::FromIterator<<V as Monoid
     #[inline(always)]
     fn from_iter<I: IntoIterator<Item = <V as Monoid>::S>>(iter: I) -> LazySegTree<V, F, A> {
         let a = iter.into_iter().collect::<Vec<_>>();
-        let n = a.len();
-        let m = n.next_power_of_two();
-        let mut vals = vec![<V as Monoid>::E; 2 * m];
-        vals[m..m + n].clone_from_slice(&a);
-        for i in (1..m).rev() {
-            vals[i] = <V as Monoid>::op(&vals[2 * i], &vals[2 * i + 1]);
-        }
         Self {
-            n,
-            m,
-            k: m.trailing_zeros(),
-            vals,
-            maps: vec![<F as Monoid>::E; 2 * m],
-            _marker: std::marker::PhantomData,
+            core: LazyCore::build(a, StaticAction(std::marker::PhantomData)),
+        }
+    }
+}
+/// Range add to `(sum, len)` values.
+///
+/// # Example
+///
+/// ```
+/// use algebra::Additive;
+/// use segtree::lazy::{AddSum, LazySegTree};
+/// type V = (Additive<i64>, Additive<i64>);
+/// let mut st = [3, 1, 4, 1, 5]
+///     .into_iter()
+///     .map(|x| (x, 1))
+///     .collect::<LazySegTree<V, Additive<i64>, AddSum<i64>>>();
+/// st.range_apply(1..4, &10);
+/// assert_eq!(st.range_fold(..).0, 44);
+/// assert_eq!(st.range_fold(3..).0, 16);
+/// ```
+///
+pub struct AddSum<T>(std::marker::PhantomData<T>);
+impl<T: Semiring<S = T> + Copy> Action<(Additive<T>, Additive<T>), Additive<T>> for AddSum<T> {
+    fn apply(val: &mut (T, T), func: &T) {
+        val.0 = T::add(&val.0, &T::mul(func, &val.1));
+    }
+}
+/// Range affine to `(sum, len)` values.
+///
+/// # Example
+///
+/// ```
+/// use algebra::{Additive, Affine};
+/// use segtree::lazy::{AffineSum, LazySegTree};
+/// type V = (Additive<i64>, Additive<i64>);
+/// let mut st = [3, 1, 4, 1, 5]
+///     .into_iter()
+///     .map(|x| (x, 1))
+///     .collect::<LazySegTree<V, Affine<i64>, AffineSum<i64>>>();
+/// st.range_apply(..3, &(2, 1));
+/// st.range_apply(2.., &(-1, 0));
+/// assert_eq!(st.range_fold(..).0, -5);
+/// assert_eq!(st.range_fold(..2).0, 10);
+/// ```
+///
+pub struct AffineSum<T>(std::marker::PhantomData<T>);
+impl<T: Semiring<S = T> + Copy> Action<(Additive<T>, Additive<T>), Affine<T>> for AffineSum<T> {
+    fn apply(val: &mut (T, T), func: &(T, T)) {
+        val.0 = T::add(&T::mul(&func.0, &val.0), &T::mul(&func.1, &val.1));
+    }
+}
+/// Range add to minimum.
+///
+/// # Example
+///
+/// ```
+/// use algebra::{Additive, Min};
+/// use segtree::lazy::{AddMin, LazySegTree};
+/// let mut st = [3, 1, 4, 1, 5]
+///     .into_iter()
+///     .collect::<LazySegTree<Min<i64>, Additive<i64>, AddMin<i64>>>();
+/// st.range_apply(..2, &5);
+/// assert_eq!(st.range_fold(..3), 4);
+/// assert_eq!(st.range_fold(..), 1);
+/// ```
+///
+pub struct AddMin<T>(std::marker::PhantomData<T>);
+impl<T: Bounded> Action<Min<T>, Additive<T>> for AddMin<T> {
+    fn apply(val: &mut T, func: &T) {
+        if *val != T::MAX {
+            *val = *val + *func;
+        }
+    }
+}
+/// Range add to maximum.
+///
+/// # Example
+///
+/// ```
+/// use algebra::{Additive, Max};
+/// use segtree::lazy::{AddMax, LazySegTree};
+/// let mut st = [3, 1, 4, 1, 5]
+///     .into_iter()
+///     .collect::<LazySegTree<Max<i64>, Additive<i64>, AddMax<i64>>>();
+/// st.range_apply(1..2, &5);
+/// assert_eq!(st.range_fold(..3), 6);
+/// assert_eq!(st.range_fold(2..), 5);
+/// ```
+///
+pub struct AddMax<T>(std::marker::PhantomData<T>);
+impl<T: Bounded> Action<Max<T>, Additive<T>> for AddMax<T> {
+    fn apply(val: &mut T, func: &T) {
+        if *val != T::MIN {
+            *val = *val + *func;
         }
     }
 }
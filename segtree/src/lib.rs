@@ -1,33 +1,56 @@
+pub mod beats;
 pub mod dual;
+pub mod dynamic;
+#[cfg(feature = "laws")]
+pub mod laws;
 pub mod lazy;
-use algebra::Monoid;
-/// Segment Tree
-///
-/// # Example
-///
-/// ```
-/// ```
-///
-pub struct SegTree<T: Monoid> {
+pub mod persistent;
+pub mod runtime;
+pub mod two_dim;
+use algebra::{Monoid, range_to_pair};
+/// Identity and operation of a monoid on `S`,
+/// which are given by a `Monoid` or at runtime.
+pub(crate) trait MonoidOps<S> {
+    fn e(&self) -> S;
+    fn op(&self, lhs: &S, rhs: &S) -> S;
+}
+/// `MonoidOps` of a `Monoid` `T`.
+pub(crate) struct StaticMonoid<T>(std::marker::PhantomData<T>);
+impl<T: Monoid> MonoidOps<T::S> for StaticMonoid<T> {
+    fn e(&self) -> T::S {
+        T::E
+    }
+    fn op(&self, lhs: &T::S, rhs: &T::S) -> T::S {
+        T::op(lhs, rhs)
+    }
+}
+/// Segment Tree over `MonoidOps`, which is shared by `SegTree` and `RuntimeSegTree`.
+pub(crate) struct Core<S, O> {
     n: usize,
     m: usize,
-    vals: Vec<T::S>,
+    ops: O,
+    vals: Vec<S>,
 }
-use std::ops::RangeBounds;
-impl<T: Monoid> SegTree<T> {
-    /// This is initializer of `SegTree`.
-    /// This function has a time complexity of O(n).
-    pub fn new(n: usize) -> Self {
+impl<S: Clone, O: MonoidOps<S>> Core<S, O> {
+    pub(crate) fn new(n: usize, ops: O) -> Self {
         let m = n.next_power_of_two();
         Self {
             n,
             m,
-            vals: vec![T::E; 2 * m],
+            vals: vec![ops.e(); 2 * m],
+            ops,
         }
     }
-    /// Update `i` th, from `a_i` to `x`.
-    /// This function has a time complexity of O(log n).
-    pub fn set_at(&mut self, i: usize, x: &T::S) {
+    pub(crate) fn build(a: Vec<S>, ops: O) -> Self {
+        let mut res = Self::new(a.len(), ops);
+        let m = res.m;
+        res.vals[m..m + a.len()].clone_from_slice(&a);
+        for i in (1..m).rev() {
+            res.vals[i] = res.ops.op(&res.vals[2 * i], &res.vals[2 * i + 1]);
+        }
+        res
+    }
+    pub(crate) fn set_at(&mut self, i: usize, x: &S) {
         use std::iter::successors;
         assert!(i < self.n);
         let mut i = i + self.m;
@@ -35,43 +58,138 @@ impl<T: Monoid> SegTree<T> {
         i >>= 1;
         successors(Some(i), |i| Some(i >> 1))
             .take_while(|i| *i > 0)
-            .for_each(|i| self.vals[i] = T::op(&self.vals[2 * i], &self.vals[2 * i + 1]));
+            .for_each(|i| self.vals[i] = self.ops.op(&self.vals[2 * i], &self.vals[2 * i + 1]));
     }
-    /// Return `a_i`.
-    /// This function has a time complexity of O(1).
-    pub fn get_at(&self, i: usize) -> &T::S {
+    pub(crate) fn get_at(&self, i: usize) -> &S {
         assert!(i < self.n);
         &self.vals[i + self.m]
     }
-    /// Return `op(a_l, ..., a_r)`.
-    /// This function has a time complexity of O(log n).
-    pub fn range_fold(&self, range: impl RangeBounds<usize>) -> T::S {
-        use std::ops::Bound::{Excluded, Included, Unbounded};
-        let mut l = match range.start_bound() {
-            Unbounded => 0,
-            Included(x) => *x,
-            Excluded(x) => x + 1,
-        } + self.m;
-        let mut r = match range.end_bound() {
-            Unbounded => self.n,
-            Included(x) => x + 1,
-            Excluded(x) => *x,
-        } + self.m;
-        let mut left = T::E;
-        let mut right = T::E;
+    pub(crate) fn range_fold(&self, range: impl RangeBounds<usize>) -> S {
+        let (l, r) = range_to_pair(range, self.n);
+        assert!(l <= r && r <= self.n);
+        let (mut l, mut r) = (l + self.m, r + self.m);
+        let mut left = self.ops.e();
+        let mut right = self.ops.e();
         while l < r {
             if l & 1 == 1 {
-                left = T::op(&left, &self.vals[l]);
+                left = self.ops.op(&left, &self.vals[l]);
                 l += 1;
             }
             if r & 1 == 1 {
                 r -= 1;
-                right = T::op(&self.vals[r], &right);
+                right = self.ops.op(&self.vals[r], &right);
             }
             l >>= 1;
             r >>= 1;
         }
-        T::op(&left, &right)
+        self.ops.op(&left, &right)
+    }
+    pub(crate) fn max_right(&self, l: usize, pred: impl Fn(&S) -> bool) -> usize {
+        assert!(l <= self.n);
+        assert!(pred(&self.ops.e()));
+        if l == self.n {
+            return self.n;
+        }
+        let mut l = l + self.m;
+        let mut sum = self.ops.e();
+        loop {
+            l >>= l.trailing_zeros();
+            let s = self.ops.op(&sum, &self.vals[l]);
+            if !pred(&s) {
+                while l < self.m {
+                    l *= 2;
+                    let s = self.ops.op(&sum, &self.vals[l]);
+                    if pred(&s) {
+                        sum = s;
+                        l += 1;
+                    }
+                }
+                return l - self.m;
+            }
+            sum = s;
+            l += 1;
+            if l.is_power_of_two() {
+                return self.n;
+            }
+        }
+    }
+    pub(crate) fn min_left(&self, r: usize, pred: impl Fn(&S) -> bool) -> usize {
+        assert!(r <= self.n);
+        assert!(pred(&self.ops.e()));
+        if r == 0 {
+            return 0;
+        }
+        let mut r = r + self.m;
+        let mut sum = self.ops.e();
+        loop {
+            r -= 1;
+            while r > 1 && r & 1 == 1 {
+                r >>= 1;
+            }
+            let s = self.ops.op(&self.vals[r], &sum);
+            if !pred(&s) {
+                while r < self.m {
+                    r = 2 * r + 1;
+                    let s = self.ops.op(&self.vals[r], &sum);
+                    if pred(&s) {
+                        sum = s;
+                        r -= 1;
+                    }
+                }
+                return r + 1 - self.m;
+            }
+            sum = s;
+            if r.is_power_of_two() {
+                return 0;
+            }
+        }
+    }
+}
+/// Segment Tree
+///
+/// # Example
+///
+/// ```
+/// ```
+///
+pub struct SegTree<T: Monoid> {
+    core: Core<T::S, StaticMonoid<T>>,
+}
+use std::ops::RangeBounds;
+impl<T: Monoid> SegTree<T> {
+    /// This is initializer of `SegTree`.
+    /// This function has a time complexity of O(n).
+    pub fn new(n: usize) -> Self {
+        Self {
+            core: Core::new(n, StaticMonoid(std::marker::PhantomData)),
+        }
+    }
+    /// Update `i` th, from `a_i` to `x`.
+    /// This function has a time complexity of O(log n).
+    pub fn set_at(&mut self, i: usize, x: &T::S) {
+        self.core.set_at(i, x);
+    }
+    /// Return `a_i`.
+    /// This function has a time complexity of O(1).
+    pub fn get_at(&self, i: usize) -> &T::S {
+        self.core.get_at(i)
+    }
+    /// Return `op(a_l, ..., a_r)`.
+    /// This function has a time complexity of O(log n).
+    pub fn range_fold(&self, range: impl RangeBounds<usize>) -> T::S {
+        self.core.range_fold(range)
+    }
+    /// Return the maximum `r` such that `pred(op(a_l, ..., a_{r - 1}))`,
+    /// where `pred` is monotone and `pred(E)` is true.
+    /// This function has a time complexity of O(log n).
+    pub fn max_right(&self, l: usize, pred: impl Fn(&T::S) -> bool) -> usize {
+        self.core.max_right(l, pred)
+    }
+    /// Return the minimum `l` such that `pred(op(a_l, ..., a_{r - 1}))`,
+    /// where `pred` is monotone and `pred(E)` is true.
+    /// This function has a time complexity of O(log n).
+    pub fn min_left(&self, r: usize, pred: impl Fn(&T::S) -> bool) -> usize {
+        self.core.min_left(r, pred)
     }
 }
 /// Constructor from iter to `SegTree`.
@@ -81,13 +199,8 @@ impl<T: Monoid> FromIterator<T::S> for SegTree<T> {
     #[inline]
     fn from_iter<I: IntoIterator<Item = T::S>>(iter: I) -> SegTree<T> {
         let a = iter.into_iter().collect::<Vec<_>>();
-        let n = a.len();
-        let m = n.next_power_of_two();
-        let mut vals = vec![T::E; 2 * m];
-        vals[m..m + n].clone_from_slice(&a);
-        (1..m)
-            .rev()
-            .for_each(|i| vals[i] = T::op(&vals[2 * i], &vals[2 * i + 1]));
-        Self { n, m, vals }
+        Self {
+            core: Core::build(a, StaticMonoid(std::marker::PhantomData)),
+        }
     }
 }
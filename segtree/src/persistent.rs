@@ -0,0 +1,155 @@
+use super::dynamic::{Node, bounds, fold};
+use algebra::Monoid;
+use std::ops::RangeBounds;
+/// Handle of a version of `PersistentSegTree`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Version(usize);
+/// Persistent Segment Tree over indices in `[lo, hi)`.
+/// Every update creates a new version and old versions remain available.
+///
+/// # Example
+///
+/// K-th smallest value in `a[l..r]` by versions of counts of `a[..i]`.
+///
+/// ```
+/// use algebra::Monoid;
+/// use segtree::persistent::PersistentSegTree;
+/// struct Sum;
+/// impl Monoid for Sum {
+///     type S = usize;
+///     const E: usize = 0;
+///     fn op(lhs: &usize, rhs: &usize) -> usize {
+///         lhs + rhs
+///     }
+/// }
+/// let a = [5i64, -3, 8, 5, 1];
+/// let mut st = PersistentSegTree::<Sum>::new(-10, 10);
+/// let mut versions = vec![st.empty()];
+/// for &x in &a {
+///     let v = *versions.last().unwrap();
+///     let c = st.get_at(v, x);
+///     versions.push(st.set_at(v, x, &(c + 1)));
+/// }
+/// let kth = |l: usize, r: usize, k: usize| {
+///     st.max_right_pair(versions[l], versions[r], |x, y| y - x <= k)
+/// };
+/// assert_eq!(kth(1, 4, 0), -3);
+/// assert_eq!(kth(1, 4, 1), 5);
+/// assert_eq!(kth(0, 5, 4), 8);
+/// ```
+///
+pub struct PersistentSegTree<T: Monoid> {
+    lo: i64,
+    hi: i64,
+    /// `nodes[0]` is the empty node.
+    nodes: Vec<Node<T::S>>,
+}
+impl<T: Monoid> PersistentSegTree<T> {
+    /// This is initializer of `PersistentSegTree`.
+    /// This function has a time complexity of O(1).
+    pub fn new(lo: i64, hi: i64) -> Self {
+        assert!(lo < hi);
+        Self {
+            lo,
+            hi,
+            nodes: vec![Node {
+                val: T::E,
+                child: [0, 0],
+            }],
+        }
+    }
+    /// Return the version with all elements `E`.
+    /// This function has a time complexity of O(1).
+    pub fn empty(&self) -> Version {
+        Version(0)
+    }
+    /// Return the version made from `v` by updating `a_i` to `x`.
+    /// This function has a time complexity of O(log(hi - lo)).
+    pub fn set_at(&mut self, v: Version, i: i64, x: &T::S) -> Version {
+        assert!(self.lo <= i && i < self.hi);
+        let (mut l, mut r) = (self.lo as i128, self.hi as i128);
+        let mut path = vec![];
+        let mut k = v.0;
+        while r - l > 1 {
+            let m = l + (r - l) / 2;
+            let d = (i as i128 >= m) as usize;
+            if d == 0 {
+                r = m
+            } else {
+                l = m
+            }
+            path.push((self.nodes[k].child, d));
+            k = self.nodes[k].child[d];
+        }
+        self.nodes.push(Node {
+            val: x.clone(),
+            child: [0, 0],
+        });
+        let mut k = self.nodes.len() - 1;
+        for (mut child, d) in path.into_iter().rev() {
+            child[d] = k;
+            self.nodes.push(Node {
+                val: T::op(&self.nodes[child[0]].val, &self.nodes[child[1]].val),
+                child,
+            });
+            k = self.nodes.len() - 1;
+        }
+        Version(k)
+    }
+    /// Return `a_i` in version `v`.
+    /// This function has a time complexity of O(log(hi - lo)).
+    pub fn get_at(&self, v: Version, i: i64) -> T::S {
+        assert!(self.lo <= i && i < self.hi);
+        let (mut l, mut r) = (self.lo as i128, self.hi as i128);
+        let mut k = v.0;
+        while k != 0 && r - l > 1 {
+            let m = l + (r - l) / 2;
+            let d = (i as i128 >= m) as usize;
+            if d == 0 {
+                r = m
+            } else {
+                l = m
+            }
+            k = self.nodes[k].child[d];
+        }
+        self.nodes[k].val.clone()
+    }
+    /// Return `op(a_l, ..., a_r)` in version `v`.
+    /// This function has a time complexity of O(log(hi - lo)).
+    pub fn range_fold(&self, v: Version, range: impl RangeBounds<i64>) -> T::S {
+        let (l, r) = bounds(range, self.lo, self.hi);
+        fold::<T>(&self.nodes, v.0, self.lo as i128, self.hi as i128, l, r)
+    }
+    /// Return the maximum `r` such that `pred(fold_u, fold_v)`, where `fold_u` and `fold_v`
+    /// are `op(a_lo, ..., a_{r - 1})` in versions `u` and `v` respectively.
+    /// `pred` must be monotone and `pred(E, E)` must be true.
+    /// This function has a time complexity of O(log(hi - lo)).
+    pub fn max_right_pair(
+        &self,
+        u: Version,
+        v: Version,
+        pred: impl Fn(&T::S, &T::S) -> bool,
+    ) -> i64 {
+        assert!(pred(&T::E, &T::E));
+        let (mut a, mut b) = (u.0, v.0);
+        if pred(&self.nodes[a].val, &self.nodes[b].val) {
+            return self.hi;
+        }
+        let (mut l, mut r) = (self.lo as i128, self.hi as i128);
+        let (mut x, mut y) = (T::E, T::E);
+        while r - l > 1 {
+            let m = l + (r - l) / 2;
+            let (ca, cb) = (self.nodes[a].child, self.nodes[b].child);
+            let s = T::op(&x, &self.nodes[ca[0]].val);
+            let t = T::op(&y, &self.nodes[cb[0]].val);
+            if pred(&s, &t) {
+                (x, y, l) = (s, t, m);
+                (a, b) = (ca[1], cb[1]);
+            } else {
+                r = m;
+                (a, b) = (ca[0], cb[0]);
+            }
+        }
+        l as i64
+    }
+}
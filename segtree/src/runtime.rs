@@ -0,0 +1,218 @@
+use super::lazy::{ActionOps, LazyCore};
+use super::{Core, MonoidOps};
+use std::ops::RangeBounds;
+/// `MonoidOps` of an identity `e` and an operation `op`.
+struct RuntimeMonoid<S, Op> {
+    e: S,
+    op: Op,
+}
+impl<S: Clone, Op: Fn(&S, &S) -> S> MonoidOps<S> for RuntimeMonoid<S, Op> {
+    fn e(&self) -> S {
+        self.e.clone()
+    }
+    fn op(&self, lhs: &S, rhs: &S) -> S {
+        (self.op)(lhs, rhs)
+    }
+}
+/// Segment Tree whose identity and operation are given at runtime.
+///
+/// # Example
+///
+/// Products of 2 x 2 matrices modulo a runtime modulus.
+///
+/// ```
+/// use segtree::runtime::RuntimeSegTree;
+/// let m = 1_000_000_007u64;
+/// let mul = move |a: &[u64; 4], b: &[u64; 4]| {
+///     [
+///         (a[0] * b[0] + a[1] * b[2]) % m,
+///         (a[0] * b[1] + a[1] * b[3]) % m,
+///         (a[2] * b[0] + a[3] * b[2]) % m,
+///         (a[2] * b[1] + a[3] * b[3]) % m,
+///     ]
+/// };
+/// let mut st = RuntimeSegTree::with_op(5, [1, 0, 0, 1], mul);
+/// for i in 0..5 {
+///     st.set_at(i, &[1, 1, 1, 0]);
+/// }
+/// assert_eq!(st.range_fold(..)[1], 5);
+/// assert_eq!(st.range_fold(1..3)[1], 1);
+/// ```
+///
+pub struct RuntimeSegTree<S, Op> {
+    core: Core<S, RuntimeMonoid<S, Op>>,
+}
+impl<S: Clone, Op: Fn(&S, &S) -> S> RuntimeSegTree<S, Op> {
+    /// This is initializer of `RuntimeSegTree` with all elements `e`.
+    /// This function has a time complexity of O(n).
+    pub fn with_op(n: usize, e: S, op: Op) -> Self {
+        Self {
+            core: Core::new(n, RuntimeMonoid { e, op }),
+        }
+    }
+    /// This is initializer of `RuntimeSegTree` from `a`.
+    /// This function has a time complexity of O(n).
+    pub fn build(a: Vec<S>, e: S, op: Op) -> Self {
+        Self {
+            core: Core::build(a, RuntimeMonoid { e, op }),
+        }
+    }
+    /// Update `i` th, from `a_i` to `x`.
+    /// This function has a time complexity of O(log n).
+    pub fn set_at(&mut self, i: usize, x: &S) {
+        self.core.set_at(i, x);
+    }
+    /// Return `a_i`.
+    /// This function has a time complexity of O(1).
+    pub fn get_at(&self, i: usize) -> &S {
+        self.core.get_at(i)
+    }
+    /// Return `op(a_l, ..., a_r)`.
+    /// This function has a time complexity of O(log n).
+    pub fn range_fold(&self, range: impl RangeBounds<usize>) -> S {
+        self.core.range_fold(range)
+    }
+    /// Return the maximum `r` such that `pred(op(a_l, ..., a_{r - 1}))`,
+    /// where `pred` is monotone and `pred(e)` is true.
+    /// This function has a time complexity of O(log n).
+    pub fn max_right(&self, l: usize, pred: impl Fn(&S) -> bool) -> usize {
+        self.core.max_right(l, pred)
+    }
+    /// Return the minimum `l` such that `pred(op(a_l, ..., a_{r - 1}))`,
+    /// where `pred` is monotone and `pred(e)` is true.
+    /// This function has a time complexity of O(log n).
+    pub fn min_left(&self, r: usize, pred: impl Fn(&S) -> bool) -> usize {
+        self.core.min_left(r, pred)
+    }
+}
+/// `ActionOps` of monoids `(e, op)` and `(id, composition)` and an action `mapping`.
+struct RuntimeAction<S, F, Op, Comp, Map> {
+    monoid: RuntimeMonoid<S, Op>,
+    id: F,
+    composition: Comp,
+    mapping: Map,
+}
+impl<S, F, Op, Comp, Map> MonoidOps<S> for RuntimeAction<S, F, Op, Comp, Map>
+where
+    S: Clone,
+    Op: Fn(&S, &S) -> S,
+{
+    fn e(&self) -> S {
+        self.monoid.e()
+    }
+    fn op(&self, lhs: &S, rhs: &S) -> S {
+        self.monoid.op(lhs, rhs)
+    }
+}
+impl<S, F, Op, Comp, Map> ActionOps<S, F> for RuntimeAction<S, F, Op, Comp, Map>
+where
+    S: Clone,
+    F: Clone,
+    Op: Fn(&S, &S) -> S,
+    Comp: Fn(&F, &F) -> F,
+    Map: Fn(&mut S, &F),
+{
+    fn id(&self) -> F {
+        self.id.clone()
+    }
+    fn composition(&self, f: &F, g: &F) -> F {
+        (self.composition)(f, g)
+    }
+    fn mapping(&self, val: &mut S, f: &F) {
+        (self.mapping)(val, f);
+    }
+}
+/// Lazy Segment Tree whose monoids and action are given at runtime.
+/// `composition(f, g)` is the map which applies `f` and then `g`.
+///
+/// # Example
+///
+/// Range affine and range sum modulo a runtime modulus, where values are `(sum, len)`.
+///
+/// ```
+/// use segtree::runtime::RuntimeLazySegTree;
+/// let m = 998_244_353u64;
+/// let mut st = RuntimeLazySegTree::build(
+///     vec![(1, 1); 5],
+///     (0, 0),
+///     |a: &(u64, u64), b: &(u64, u64)| ((a.0 + b.0) % m, a.1 + b.1),
+///     (1, 0),
+///     |f: &(u64, u64), g: &(u64, u64)| (f.0 * g.0 % m, (g.0 * f.1 + g.1) % m),
+///     |x: &mut (u64, u64), f: &(u64, u64)| x.0 = (f.0 * x.0 + f.1 * x.1) % m,
+/// );
+/// st.range_apply(1..4, &(2, 3));
+/// assert_eq!(st.range_fold(..).0, 17);
+/// st.range_apply(..2, &(m - 1, 0));
+/// assert_eq!(st.range_fold(..3).0, m - 1);
+/// ```
+///
+pub struct RuntimeLazySegTree<S, F, Op, Comp, Map> {
+    core: LazyCore<S, F, RuntimeAction<S, F, Op, Comp, Map>>,
+}
+impl<S, F, Op, Comp, Map> RuntimeLazySegTree<S, F, Op, Comp, Map>
+where
+    S: Clone,
+    F: Clone,
+    Op: Fn(&S, &S) -> S,
+    Comp: Fn(&F, &F) -> F,
+    Map: Fn(&mut S, &F),
+{
+    /// This is initializer of `RuntimeLazySegTree` with all elements `e`.
+    /// This function has a time complexity of O(n).
+    pub fn with_op(n: usize, e: S, op: Op, id: F, composition: Comp, mapping: Map) -> Self {
+        let ops = RuntimeAction {
+            monoid: RuntimeMonoid { e, op },
+            id,
+            composition,
+            mapping,
+        };
+        Self {
+            core: LazyCore::new(n, ops),
+        }
+    }
+    /// This is initializer of `RuntimeLazySegTree` from `a`.
+    /// This function has a time complexity of O(n).
+    pub fn build(a: Vec<S>, e: S, op: Op, id: F, composition: Comp, mapping: Map) -> Self {
+        let ops = RuntimeAction {
+            monoid: RuntimeMonoid { e, op },
+            id,
+            composition,
+            mapping,
+        };
+        Self {
+            core: LazyCore::build(a, ops),
+        }
+    }
+    /// Return the value of index `i`.
+    /// This function has a time complexity of O(log n).
+    pub fn get(&mut self, i: usize) -> &S {
+        self.core.get(i)
+    }
+    /// Update the value of index `i` to `x`.
+    /// This function has a time complexity of O(log n).
+    pub fn update(&mut self, i: usize, x: &S) {
+        self.core.update(i, x);
+    }
+    /// Return the fold by op in `range`.
+    /// This function has a time complexity of O(log n).
+    pub fn range_fold(&mut self, range: impl RangeBounds<usize>) -> S {
+        self.core.range_fold(range)
+    }
+    /// Apply `f` to the value which index is in `range`.
+    /// This function has a time complexity of O(log n).
+    pub fn range_apply(&mut self, range: impl RangeBounds<usize>, f: &F) {
+        self.core.range_apply(range, f);
+    }
+    /// Return the maximum `r` such that `pred(op(a_l, ..., a_{r - 1}))`,
+    /// where `pred` is monotone and `pred(e)` is true.
+    /// This function has a time complexity of O(log n).
+    pub fn max_right(&mut self, l: usize, pred: impl Fn(&S) -> bool) -> usize {
+        self.core.max_right(l, pred)
+    }
+    /// Return the minimum `l` such that `pred(op(a_l, ..., a_{r - 1}))`,
+    /// where `pred` is monotone and `pred(e)` is true.
+    /// This function has a time complexity of O(log n).
+    pub fn min_left(&mut self, r: usize, pred: impl Fn(&S) -> bool) -> usize {
+        self.core.min_left(r, pred)
+    }
+}
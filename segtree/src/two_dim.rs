@@ -0,0 +1,147 @@
+use algebra::{ComMonoid, range_to_pair};
+use std::ops::RangeBounds;
+/// Two-dimensional Segment Tree over an `h` x `w` grid.
+/// `T` must be commutative.
+///
+/// # Example
+///
+/// ```
+/// use algebra::{ComMonoid, Monoid};
+/// use segtree::two_dim::SegTree2D;
+/// struct Max;
+/// impl Monoid for Max {
+///     type S = i64;
+///     const E: i64 = i64::MIN;
+///     fn op(lhs: &i64, rhs: &i64) -> i64 {
+///         *lhs.max(rhs)
+///     }
+/// }
+/// impl ComMonoid for Max {}
+/// let mut st = SegTree2D::<Max>::build(&[vec![3, 1, 4], vec![1, 5, 9]]);
+/// assert_eq!(st.range_fold(.., ..2), 5);
+/// st.set_at(0, 0, &7);
+/// assert_eq!(st.range_fold(..1, ..), 7);
+/// assert_eq!(st.range_fold(1.., 2..), 9);
+/// ```
+///
+pub struct SegTree2D<T: ComMonoid> {
+    h: usize,
+    w: usize,
+    mh: usize,
+    mw: usize,
+    vals: Vec<T::S>,
+}
+impl<T: ComMonoid> SegTree2D<T> {
+    /// This is initializer of `SegTree2D` with all elements `E`.
+    /// This function has a time complexity of O(h w).
+    pub fn new(h: usize, w: usize) -> Self {
+        let (mh, mw) = (h.next_power_of_two(), w.next_power_of_two());
+        Self {
+            h,
+            w,
+            mh,
+            mw,
+            vals: vec![T::E; 4 * mh * mw],
+        }
+    }
+    /// This is initializer of `SegTree2D` from a grid.
+    /// This function has a time complexity of O(h w).
+    pub fn build(a: &[Vec<T::S>]) -> Self {
+        let h = a.len();
+        let w = a.first().map_or(0, |r| r.len());
+        let mut res = Self::new(h, w);
+        let mw2 = 2 * res.mw;
+        for (i, r) in a.iter().enumerate() {
+            assert_eq!(r.len(), w);
+            let row = (i + res.mh) * mw2;
+            res.vals[row + res.mw..row + res.mw + w].clone_from_slice(r);
+        }
+        for i in res.mh..2 * res.mh {
+            for j in (1..res.mw).rev() {
+                res.vals[i * mw2 + j] =
+                    T::op(&res.vals[i * mw2 + 2 * j], &res.vals[i * mw2 + 2 * j + 1]);
+            }
+        }
+        for i in (1..res.mh).rev() {
+            for j in 1..mw2 {
+                res.vals[i * mw2 + j] =
+                    T::op(&res.vals[2 * i * mw2 + j], &res.vals[(2 * i + 1) * mw2 + j]);
+            }
+        }
+        res
+    }
+    /// Update `(i, j)` th, from `a_ij` to `x`.
+    /// This function has a time complexity of O(log h log w).
+    pub fn set_at(&mut self, i: usize, j: usize, x: &T::S) {
+        assert!(i < self.h && j < self.w);
+        let mw2 = 2 * self.mw;
+        let (i, j) = (i + self.mh, j + self.mw);
+        self.vals[i * mw2 + j] = x.clone();
+        let mut c = j >> 1;
+        while c > 0 {
+            self.vals[i * mw2 + c] =
+                T::op(&self.vals[i * mw2 + 2 * c], &self.vals[i * mw2 + 2 * c + 1]);
+            c >>= 1;
+        }
+        let mut r = i >> 1;
+        while r > 0 {
+            let mut c = j;
+            while c > 0 {
+                self.vals[r * mw2 + c] = T::op(
+                    &self.vals[2 * r * mw2 + c],
+                    &self.vals[(2 * r + 1) * mw2 + c],
+                );
+                c >>= 1;
+            }
+            r >>= 1;
+        }
+    }
+    /// Return `a_ij`.
+    /// This function has a time complexity of O(1).
+    pub fn get_at(&self, i: usize, j: usize) -> &T::S {
+        assert!(i < self.h && j < self.w);
+        &self.vals[(i + self.mh) * 2 * self.mw + j + self.mw]
+    }
+    /// Return the fold of `a_ij` for `i` in `rows` and `j` in `cols`.
+    /// This function has a time complexity of O(log h log w).
+    pub fn range_fold(&self, rows: impl RangeBounds<usize>, cols: impl RangeBounds<usize>) -> T::S {
+        let (l, r) = range_to_pair(rows, self.h);
+        let (cl, cr) = range_to_pair(cols, self.w);
+        assert!(l <= r && r <= self.h && cl <= cr && cr <= self.w);
+        let (mut l, mut r) = (l + self.mh, r + self.mh);
+        let mut res = T::E;
+        while l < r {
+            if l & 1 == 1 {
+                res = T::op(&res, &self.row_fold(l, cl, cr));
+                l += 1;
+            }
+            if r & 1 == 1 {
+                r -= 1;
+                res = T::op(&res, &self.row_fold(r, cl, cr));
+            }
+            l >>= 1;
+            r >>= 1;
+        }
+        res
+    }
+    /// Return the fold of columns `[l, r)` of row node `i`.
+    /// This function has a time complexity of O(log w).
+    fn row_fold(&self, i: usize, l: usize, r: usize) -> T::S {
+        let row = &self.vals[i * 2 * self.mw..(i + 1) * 2 * self.mw];
+        let (mut l, mut r) = (l + self.mw, r + self.mw);
+        let mut res = T::E;
+        while l < r {
+            if l & 1 == 1 {
+                res = T::op(&res, &row[l]);
+                l += 1;
+            }
+            if r & 1 == 1 {
+                r -= 1;
+                res = T::op(&res, &row[r]);
+            }
+            l >>= 1;
+            r >>= 1;
+        }
+        res
+    }
+}